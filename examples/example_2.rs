@@ -1,7 +1,9 @@
 //! Example showing how to spawn a vertical scrollbar from the scrollable node.
 
 use bevy::{ecs::spawn::SpawnIter, prelude::*};
-use bevy_scrollbar::{DragSpeed, ScrollSpeed, Scrollable, ScrollbarPlugin, ThumbColor};
+use bevy_scrollbar::{
+    DragSpeed, ScrollSpeed, Scrollable, ScrollbarPlugin, ThumbColor, WheelAcceleration,
+};
 
 fn main() {
     App::new()
@@ -46,8 +48,9 @@ fn setup(mut commands: Commands) {
                 Children::spawn_one(Text::new(format!("Scroll {i}!"))),
             )
         }))),
-        // Customize scroll speed of the content
+        // Customize scroll speed of the content and accelerate fast consecutive wheel notches
         ScrollSpeed(2.0),
+        WheelAcceleration::default(),
         // Spawn the scrollbar
         Scrollable::spawn_one((
             // Add the scrollbar as a child of the container
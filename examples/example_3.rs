@@ -1,7 +1,7 @@
 //! Example showing how to spawn an horizontal scrollbar from a scrollable node.
 
 use bevy::{ecs::spawn::SpawnIter, prelude::*};
-use bevy_scrollbar::{DragSpeed, ScrollSpeed, Scrollable, ScrollbarPlugin, ThumbColor};
+use bevy_scrollbar::{DragSpeed, ScrollSpeed, Scrollable, ScrollbarPlugin, ThumbColor, ThumbStyle};
 
 fn main() {
     App::new()
@@ -69,6 +69,10 @@ fn setup(mut commands: Commands) {
             BorderColor::all(Color::BLACK),
             // Customize color of the thumb
             ThumbColor(Color::srgb(0.0, 1.0, 0.0)),
+            // Customize border and outline of the thumb
+            ThumbStyle::default()
+                .with_border(UiRect::all(Val::Px(2.0)), Color::srgb(0.0, 0.4, 0.0))
+                .with_outline(Outline::new(Val::Px(1.0), Val::ZERO, Color::WHITE)),
             // Customize drag speed of the thumb
             DragSpeed(6.0),
         )),
@@ -0,0 +1,67 @@
+//! Example showing how to snap an horizontal carousel to its cards.
+
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+use bevy_scrollbar::{ScrollSnap, ScrollSpeed, Scrollable, ScrollbarPlugin, SnapAlign};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, ScrollbarPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // Container of the scrollable content and its scrollbar
+    let mut container = commands.spawn(Node {
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        // We want the scrollbar under the scrollable content
+        flex_direction: FlexDirection::Column,
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    });
+    let container_id = container.id();
+
+    // Spawn the carousel
+    container.with_child((
+        Node {
+            width: Val::Percent(60.0),
+            height: Val::Percent(40.0),
+            border: UiRect::all(Val::Px(5.0)),
+            overflow: Overflow::scroll_x(),
+            column_gap: Val::Px(20.0),
+            ..default()
+        },
+        BorderColor::all(Color::BLACK),
+        Children::spawn(SpawnIter((0..20).map(|i| {
+            (
+                Node {
+                    min_width: Val::Percent(40.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::hsl(18.0 * i as f32, 0.6, 0.4)),
+                Children::spawn_one(Text::new(format!("Card {i}"))),
+            )
+        }))),
+        ScrollSpeed(20.0),
+        // Center the closest card once scrolling settles
+        ScrollSnap::new(SnapAlign::Center),
+        Scrollable::spawn_one((
+            ChildOf(container_id),
+            Node {
+                width: Val::Percent(60.0),
+                height: Val::Percent(3.0),
+                margin: UiRect::top(Val::Px(5.0)),
+                border: UiRect::all(Val::Px(5.0)),
+                ..default()
+            },
+            BorderColor::all(Color::BLACK),
+        )),
+    ));
+}
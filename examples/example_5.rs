@@ -0,0 +1,52 @@
+//! Example showing how to display a long list of which only the visible rows are spawned.
+
+use bevy::prelude::*;
+use bevy_scrollbar::{RowHeight, Scrollable, ScrollbarPlugin, VirtualList};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, ScrollbarPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // Container of the list and its scrollbar
+    let mut container = commands.spawn(Node {
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    });
+    let container_id = container.id();
+
+    // Spawn the list
+    container.with_child((
+        Node {
+            width: Val::Percent(40.0),
+            height: Val::Percent(80.0),
+            border: UiRect::all(Val::Px(5.0)),
+            overflow: Overflow::scroll_y(),
+            ..default()
+        },
+        BorderColor::all(Color::BLACK),
+        // Only the rows intersecting the viewport are spawned
+        VirtualList::new(100_000, RowHeight::Fixed(30.0), |index, row| {
+            row.insert(Children::spawn_one(Text::new(format!("  Row {index}"))));
+        }),
+        Scrollable::spawn_one((
+            ChildOf(container_id),
+            Node {
+                width: Val::Percent(1.5),
+                height: Val::Percent(80.0),
+                margin: UiRect::left(Val::Px(5.0)),
+                border: UiRect::all(Val::Px(5.0)),
+                ..default()
+            },
+            BorderColor::all(Color::BLACK),
+        )),
+    ));
+}
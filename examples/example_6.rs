@@ -0,0 +1,98 @@
+//! Example showing how to display a table whose header row and first column stay in view.
+
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+use bevy_scrollbar::{
+    ScrollTable, ScrollTableBody, ScrollTableColumns, ScrollTableCorner, ScrollTableHeader,
+    ScrollbarPlugin,
+};
+
+const ROWS: usize = 100;
+const COLUMNS: usize = 20;
+const CELL_WIDTH: f32 = 100.0;
+const CELL_HEIGHT: f32 = 30.0;
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, ScrollbarPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // Container of the table
+    let mut container = commands.spawn(Node {
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    });
+
+    // Spawn the table. Its scrollbars are spawned by the plugin
+    container.with_child((
+        Node {
+            width: Val::Percent(60.0),
+            height: Val::Percent(80.0),
+            border: UiRect::all(Val::Px(5.0)),
+            ..default()
+        },
+        BorderColor::all(Color::BLACK),
+        ScrollTable::default()
+            .with_header_height(CELL_HEIGHT)
+            .with_columns_width(CELL_WIDTH)
+            .with_scrollbar_width(10.0),
+        children![
+            (ScrollTableCorner, Children::spawn_one(Text::new("#"))),
+            // The header row lists the column names
+            (
+                ScrollTableHeader,
+                Children::spawn(SpawnIter(
+                    (0..COLUMNS).map(|column| cell(format!("Column {column}")))
+                )),
+            ),
+            // The frozen column lists the row names
+            (
+                ScrollTableColumns,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                Children::spawn(SpawnIter((0..ROWS).map(|row| cell(format!("Row {row}"))))),
+            ),
+            // The body lists the cells, row by row
+            (
+                ScrollTableBody,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                Children::spawn(SpawnIter((0..ROWS).map(|row| {
+                    (
+                        Node {
+                            flex_shrink: 0.0,
+                            ..default()
+                        },
+                        Children::spawn(SpawnIter(
+                            (0..COLUMNS).map(move |column| cell(format!("{row}:{column}"))),
+                        )),
+                    )
+                }))),
+            ),
+        ],
+    ));
+}
+
+/// Returns a cell of the table holding `text`.
+fn cell(text: String) -> impl Bundle {
+    (
+        Node {
+            width: Val::Px(CELL_WIDTH),
+            height: Val::Px(CELL_HEIGHT),
+            flex_shrink: 0.0,
+            ..default()
+        },
+        Children::spawn_one(Text::new(text)),
+    )
+}
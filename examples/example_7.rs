@@ -0,0 +1,66 @@
+//! Example showing how to scroll a carousel one page at a time and show its pages as dots.
+
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+use bevy_scrollbar::{PageIndicator, Paged, Scrollable, ScrollbarPlugin};
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, ScrollbarPlugin))
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    // Container of the carousel and its page indicator
+    let container = commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        })
+        .id();
+
+    // Spawn the carousel, whose pages are as wide as it is
+    let carousel = commands
+        .spawn((
+            ChildOf(container),
+            Node {
+                width: Val::Px(400.0),
+                height: Val::Px(250.0),
+                overflow: Overflow::scroll_x(),
+                ..default()
+            },
+            Paged::default(),
+            Children::spawn(SpawnIter((0..6).map(|page| {
+                (
+                    Node {
+                        width: Val::Px(400.0),
+                        flex_shrink: 0.0,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::hsl(60.0 * page as f32, 0.5, 0.4)),
+                    Children::spawn_one(Text::new(format!("Page {page}"))),
+                )
+            }))),
+            // The scrollbar is hidden, the dots showing the pages instead
+            Scrollable::spawn_one((Node::default(), Visibility::Hidden)),
+        ))
+        .id();
+
+    // Spawn the page indicator below the carousel
+    commands.spawn((
+        ChildOf(container),
+        Node {
+            margin: UiRect::top(Val::Px(10.0)),
+            ..default()
+        },
+        PageIndicator::new(carousel),
+    ));
+}
@@ -78,11 +78,57 @@
 //!
 //! # The [`Scrollable`] content
 //!
-//! The [`Scrollable`] content responds to mouse `Scroll` triggers. You can configure how fast the content scrolls by adding [`ScrollSpeed`] to the [`Scrollable`] node. See [example-2](crate#example-2).
+//! The [`Scrollable`] content responds to mouse `Scroll` triggers. You can configure how fast the content scrolls by adding [`ScrollSpeed`] to the [`Scrollable`] node. Line-based (notched wheels) and pixel-based (touchpads) scrolls can be given their own multipliers with [`ScrollSpeedPerUnit`] and consecutive notches can be accelerated with [`WheelAcceleration`]. The direction of the scrolling can be inverted, and vertical mouse scrolls can be mapped to horizontal content or not, with [`WheelDirection`], either per [`Scrollable`] node or globally as a resource. See [example-2](crate#example-2).
+//!
+//! Content that is empty, zero-sized or fits in the node cannot be scrolled and the thumb then fills the whole track. [`ScrollMetrics::is_overflowing`] tells whether the content overflows the node, for instance to hide the [`Scrollbar`] when it does not.
+//!
+//! The [`ScrollMetrics`] of a [`Scrollable`] node hold the size of its viewport and content, its largest scroll offset, its scroll progress and the rectangle of its thumb, so UI code can read them instead of deriving them from `ComputedNode`. Its [`ScrollProgress`] holds the scroll position along its scrolling axis as a fraction from `0.0` to `1.0`, and writing it scrolls the content to that fraction.
+//!
+//! # Linked scrolling
+//!
+//! Nodes sharing a [`ScrollLink`] group on an axis scroll together along it, either by the same offset in pixels or by the same fraction of their content, e.g. to keep a table header or a line number gutter aligned with its content. See [`ScrollLinkMode`].
+//!
+//! # Sticky headers
+//!
+//! Children of a [`Scrollable`] node marked [`StickyHeader`] pin at the start of its viewport while their section is scrolled through, and are pushed out by the next header. This suits long grouped lists such as inventory categories or settings sections.
+//!
+//! # Wrap-around scrolling
+//!
+//! Adding [`WrapAround`] to a [`Scrollable`] node, e.g. a horizontal carousel, makes scrolling past the end of its content continue from its start and the other way around. Its thumb wraps around the track along with the content.
+//!
+//! # Paged scrolling
+//!
+//! Adding [`Paged`] to a [`Scrollable`] node scrolls it one viewport long page at a time, with an animation, when scrolling the mouse, dragging the thumb or, with the `gamepad` feature, flicking the left stick of a gamepad. A [`PageIndicator`] shows its pages as dots, highlighting the current one and jumping to a page when its dot is clicked. See [example-7](https://github.com/gwafotapa/bevy_scrollbar/blob/main/examples/example_7.rs).
+//!
+//! # Scroll-driven animations
+//!
+//! A [`ScrollTimeline`] ties an animation, e.g. a parallax background, a collapsing header or a fade-in, to the scroll progress of a [`Scrollable`] node. It samples a Bevy `Curve` with that progress whenever the node scrolls and hands the sample to a callback writing it to its entity.
+//!
+//! # Scroll tables
+//!
+//! [`ScrollTable`] lays out a table whose header row only follows the horizontal scroll of its body and whose frozen columns only follow its vertical scroll. See [example-6](https://github.com/gwafotapa/bevy_scrollbar/blob/main/examples/example_6.rs).
+//!
+//! # Scroll snapping
+//!
+//! Adding [`ScrollSnap`] to a [`Scrollable`] node snaps its content to the start, center or end of its closest child once wheel or drag scrolling settles. See [example-4](https://github.com/gwafotapa/bevy_scrollbar/blob/main/examples/example_4.rs).
+//!
+//! # Virtual lists
+//!
+//! Adding [`VirtualList`] to a vertical [`Scrollable`] node displays a list of rows of which only the visible ones are spawned, which keeps very long lists cheap to lay out. See [example-5](https://github.com/gwafotapa/bevy_scrollbar/blob/main/examples/example_5.rs).
+//!
+//! Content that is only partially laid out, such as virtualized or procedurally generated content, can override the content size computed by the layout with [`VirtualContentSize`]. The [`ScrollPosition`] of the node then holds the virtual offset of its viewport.
 //!
 //! # Thumb customization
 //!
-//! Color and `Drag` speed of the thumb can be configured by adding [`ThumbColor`] and [`DragSpeed`] to the [`Scrollbar`]. See [example-2](crate#example-2).
+//! Color and `Drag` speed of the thumb can be configured by adding [`ThumbColor`] and [`DragSpeed`] to the [`Scrollbar`]. See [example-2](crate#example-2). Its border radius and the gap between it and the sides of the track can be configured with [`ThumbRadius`] and [`ThumbInset`]. These components can be mutated at runtime, e.g. to switch themes, and the thumb follows.
+//!
+//! The thumb can also be textured, nine-sliced, bordered, outlined and given a centered grip icon with [`ThumbStyle`]. See [example-3](https://github.com/gwafotapa/bevy_scrollbar/blob/main/examples/example_3.rs).
+//!
+//! To use your own thumb instead, for instance one with icons or labels as children, relate it to the [`Scrollbar`] with [`ThumbOf`], e.g. by spawning it along with the [`Scrollbar`] through `Thumb::spawn_one`. The [`Scrollbar`] then only manages the length and position of the thumb along the track.
+//!
+//! # Testing
+//!
+//! The `testing` feature exposes the `testing` module, which simulates wheel scrolls, thumb drags and trough clicks on scrollbars without a window.
 //!
 //! # Example 2
 //!
@@ -147,11 +193,11 @@
 //!                 height: Val::Percent(80.0),
 //!                 margin: UiRect::left(Val::Px(5.0)),
 //!                 border: UiRect::all(Val::Px(5.0)),
+//!                 // The thumb will be spawned with the same border radius
+//!                 border_radius: BorderRadius::all(Val::Px(10.0)),
 //!                 ..default()
 //!             },
 //!             BorderColor::all(Color::BLACK),
-//!             // The thumb will be spawned with the same border radius
-//!             BorderRadius::all(Val::Px(10.0)),
 //!             // Customize color of the thumb
 //!             ThumbColor(Color::srgb(0.0, 0.0, 1.0)),
 //!             // Customize drag speed of the thumb
@@ -161,112 +207,321 @@
 //! }
 //!```
 
+mod link;
+mod paged;
 mod scrollable;
 mod scrollbar;
+mod snap;
+mod sticky;
+mod table;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod thumb;
+mod timeline;
+mod virtual_list;
+mod wrap;
 
 use bevy::{prelude::*, ui::UiSystems};
+pub use link::{ScrollLink, ScrollLinkMode};
 use log::debug;
-pub use scrollable::{ScrollSpeed, Scrollable, ScrollableLineHeight};
-pub use scrollbar::{DragSpeed, Scrollbar, ThumbColor};
+pub use paged::{PageIndicator, Paged};
+use scrollable::{LastContentSize, content_size, max_offset, progress, thumb_ratio};
+pub use scrollable::{
+    ScrollMetrics, ScrollProgress, ScrollSpeed, ScrollSpeedPerUnit, Scrollable,
+    ScrollableLineHeight, VirtualContentSize, WheelAcceleration, WheelDirection,
+};
+use scrollbar::TrackGeometry;
+pub use scrollbar::{
+    DragSpeed, Scrollbar, ThumbColor, ThumbGrip, ThumbInset, ThumbRadius, ThumbStyle,
+};
+pub use snap::{ScrollSnap, SnapAlign, SnapStrictness};
+pub use sticky::StickyHeader;
+pub use table::{
+    ScrollTable, ScrollTableBody, ScrollTableColumns, ScrollTableCorner, ScrollTableHeader,
+};
+pub use thumb::{Thumb, ThumbOf};
+pub use timeline::ScrollTimeline;
+pub use virtual_list::{RowHeight, VirtualList, VirtualRow};
+pub use wrap::WrapAround;
 
-/// Plugin scheduling [`ScrollbarSystems`] after `UiSystem::Layout` in `PostUpdate`.
+/// Plugin scheduling [`ScrollbarPreLayoutSystems`] before `UiSystems::Layout` and [`ScrollbarSystems`] after it in `PostUpdate`.
+///
+/// The systems updating the thumb run between `UiSystems::Layout` and `UiSystems::PostLayout`, so systems ordered after [`ScrollbarSystems`] see the thumb where it is drawn.
+///
+/// It also initializes the [`WheelDirection`] resource and adds the observers retargeting a [`Scrollbar`] inserted with a new [`Scrollable`] and holding the snapping of pressed [`ScrollSnap`] nodes.
 pub struct ScrollbarPlugin;
 
-/// `SystemSet` containing the system updating the thumb of a [`Scrollbar`].
+/// `SystemSet` containing the systems updating the thumb of a [`Scrollbar`] and the scroll position of its [`Scrollable`].
+///
+/// This set runs in `PostUpdate` between `UiSystems::Layout` and `UiSystems::PostLayout`.
 #[derive(SystemSet, Clone, Eq, PartialEq, Hash, Debug)]
 pub struct ScrollbarSystems;
 
+/// `SystemSet` containing the systems moving the content of [`Scrollable`] nodes, e.g. to snap, link, wrap or turn pages, and styling the thumbs before they are laid out.
+///
+/// This set runs in `PostUpdate` before `UiSystems::Layout`, so what it moves is laid out in the same frame.
+#[derive(SystemSet, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ScrollbarPreLayoutSystems;
+
 impl Plugin for ScrollbarPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            PostUpdate,
-            update_scroll_position_and_thumb
-                .after(UiSystems::Layout)
-                .in_set(ScrollbarSystems),
-        );
+        app.init_resource::<WheelDirection>()
+            .add_observer(scrollbar::retarget_scrollbar)
+            .add_observer(snap::hold_snap_on_press)
+            .configure_sets(
+                PostUpdate,
+                (
+                    ScrollbarPreLayoutSystems.before(UiSystems::Layout),
+                    ScrollbarSystems
+                        .after(UiSystems::Layout)
+                        .before(UiSystems::PostLayout),
+                ),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    (
+                        (scrollbar::reorient_thumbs, scrollbar::style_thumbs).chain(),
+                        scrollable::scroll_to_progress,
+                        snap::snap_scroll_position,
+                        link::link_scroll_positions
+                            .after(scrollable::scroll_to_progress)
+                            .after(snap::snap_scroll_position),
+                        virtual_list::update_virtual_lists,
+                        table::lay_out_tables,
+                        wrap::wrap_content_around
+                            .after(scrollable::scroll_to_progress)
+                            .after(link::link_scroll_positions),
+                        (
+                            #[cfg(feature = "gamepad")]
+                            paged::turn_pages_with_sticks,
+                            paged::turn_pages,
+                            paged::update_page_indicators,
+                        )
+                            .chain()
+                            .before(link::link_scroll_positions),
+                        timeline::sample_scroll_timelines
+                            .after(link::link_scroll_positions)
+                            .after(wrap::wrap_content_around),
+                    )
+                        .in_set(ScrollbarPreLayoutSystems),
+                    (update_scroll_position_and_thumb, sticky::pin_sticky_headers)
+                        .in_set(ScrollbarSystems),
+                ),
+            );
     }
 }
 
 /// Clamps [`ScrollPosition`] and updates the length and position of the thumb.
 ///
-/// Bevy computes layout and `Transform` of UI nodes in `UiSystems::Layout`. This system runs in `PostUpdate` after `UiSystems::Layout` and uses change detection on the [`Scrollable`] node. Graphically, the thumb is updated on the frame following the change. This allows us to use the computation done by `UiSystems::Layout`.
+/// Bevy computes layout and `Transform` of UI nodes in `UiSystems::Layout`. This system runs in `PostUpdate` after `UiSystems::Layout` and only updates the thumb of a [`Scrollable`] node whose content size or scroll position changed. This allows us to use the computation done by `UiSystems::Layout`. The `Node` of the thumb is updated for the next layout and, so the thumb is not drawn a frame late, its `ComputedNode` and `UiGlobalTransform` are updated to their final values right away.
 fn update_scroll_position_and_thumb(
-    q_changed_scrollable: Query<
-        (&Scrollable, &Node, Ref<ComputedNode>),
-        Or<(Changed<ComputedNode>, Changed<ScrollPosition>)>,
-    >,
-    q_children: Query<&Children>,
-    mut q_node: Query<&mut Node, Without<Scrollable>>,
+    mut q_scrollable: Query<(
+        &Scrollable,
+        Ref<Node>,
+        Ref<ComputedNode>,
+        Ref<ScrollPosition>,
+        Option<Ref<VirtualContentSize>>,
+        &mut LastContentSize,
+    )>,
+    q_scrollbar: Query<(&Thumb, Ref<ThumbInset>, Ref<Node>, Ref<ComputedNode>), With<Scrollbar>>,
+    mut q_thumb: Query<(&mut Node, &mut ComputedNode), (Without<Scrollable>, Without<Scrollbar>)>,
     mut commands: Commands,
 ) -> Result {
-    for (scrollable, scrollable_node, scrollable_cnode) in &q_changed_scrollable {
-        let thumb = q_children.get(scrollable.scrollbar())?[0];
+    for (
+        scrollable,
+        scrollable_node,
+        scrollable_cnode,
+        scroll_position,
+        virtual_content_size,
+        mut last_content_size,
+    ) in &mut q_scrollable
+    {
+        let (thumb, inset, track_node, track_cnode) = q_scrollbar.get(scrollable.scrollbar())?;
+        // The layout updates the content size without triggering change detection
+        let content_size = content_size(&scrollable_cnode, virtual_content_size.as_deref());
+        // The overflowing axis may have changed along with the node
+        let content_changed = scrollable_node.is_changed()
+            || scrollable_cnode.is_changed()
+            || content_size != last_content_size.0
+            || virtual_content_size
+                .as_ref()
+                .is_some_and(|size| size.is_changed());
+        // The layout updates the border and padding of the track without triggering change detection either
+        let track_changed =
+            track_node.is_changed() || track_cnode.is_changed() || inset.is_changed();
+        if !content_changed && !track_changed && !scroll_position.is_changed() {
+            continue;
+        }
+
+        let thumb = thumb.thumb();
         commands.run_system_cached_with(update_scroll_and_thumb_positions, thumb);
 
-        // Recompute thumb length only if the content changed, not if it was merely scrolled
-        if scrollable_cnode.is_changed() {
-            let mut thumb_node = q_node.get_mut(thumb)?;
-            if scrollable_node.overflow.y == OverflowAxis::Scroll {
-                let ratio = scrollable_cnode.size.y / scrollable_cnode.content_size.y;
-                thumb_node.height = Val::Percent(ratio * 100.0);
-            } else if scrollable_node.overflow.x == OverflowAxis::Scroll {
-                let ratio = scrollable_cnode.size.x / scrollable_cnode.content_size.x;
-                thumb_node.width = Val::Percent(ratio * 100.0);
+        // Recompute thumb length only if the content or the track changed, not if the content was merely scrolled
+        if content_changed || track_changed {
+            let Some(axis) = scroll_axis(&scrollable_node) else {
+                continue;
+            };
+            let ratio = thumb_ratio(scrollable_cnode.size[axis], content_size[axis]);
+            let geometry = TrackGeometry::new(&track_cnode, axis, inset.0, 0.0);
+            let length = ratio * geometry.lane_length;
+            // The thumb is absolutely positioned, so its length is relative to the padding box of the track
+            let padding_box_length = track_cnode.size[axis]
+                - (track_cnode.border.min_inset[axis] + track_cnode.border.max_inset[axis]);
+            let percent = if padding_box_length > 0.0 {
+                Val::Percent(100.0 * length / padding_box_length)
+            } else {
+                Val::ZERO
+            };
+            let (mut thumb_node, mut thumb_cnode) = q_thumb.get_mut(thumb)?;
+            match axis {
+                1 => thumb_node.height = percent,
+                _ => thumb_node.width = percent,
             }
+            thumb_cnode.size[axis] = length;
+            thumb_cnode.unrounded_size[axis] = length;
+            last_content_size.set_if_neq(LastContentSize(content_size));
         }
     }
     Ok(())
 }
 
-/// Clamps [`ScrollPosition`] and updates the position of the thumb.
+/// Returns the index of the overflowing axis of a [`Scrollable`] node, if any.
+fn scroll_axis(node: &Node) -> Option<usize> {
+    if node.overflow.y == OverflowAxis::Scroll {
+        Some(1)
+    } else if node.overflow.x == OverflowAxis::Scroll {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+/// Clamps [`ScrollPosition`], unless the content wraps around, and updates the position of the thumb.
 fn update_scroll_and_thumb_positions(
     In(thumb): In<Entity>,
-    mut q_thumb: Query<(&mut Node, &ComputedNode, &ChildOf), Without<Scrollable>>,
-    q_scrollbar: Query<(&Scrollbar, &ComputedNode)>,
-    mut q_scrollable: Query<(&mut ScrollPosition, &Node, &ComputedNode), With<Scrollable>>,
+    mut q_thumb: Query<(&mut Node, &ComputedNode, &ThumbOf), Without<Scrollable>>,
+    q_scrollbar: Query<(&Scrollbar, &ComputedNode, &UiGlobalTransform, &ThumbInset)>,
+    mut q_scrollable: Query<
+        (
+            &mut ScrollPosition,
+            &Node,
+            &ComputedNode,
+            Option<&VirtualContentSize>,
+            &mut ScrollMetrics,
+            &mut ScrollProgress,
+            Option<&WrapAround>,
+        ),
+        With<Scrollable>,
+    >,
+    mut q_transform: Query<&mut UiGlobalTransform, (Without<Scrollbar>, Without<Scrollable>)>,
+    q_children: Query<&Children>,
 ) -> Result {
-    let (mut thumb_node, thumb_cnode, child_of) = q_thumb.get_mut(thumb)?;
-    let scrollbar = child_of.parent();
-    let (&Scrollbar { scrollable }, track_cnode) = q_scrollbar.get(scrollbar)?;
-    let (mut scroll_position, scrollable_node, scrollable_cnode) =
-        q_scrollable.get_mut(scrollable)?;
+    let (mut thumb_node, thumb_cnode, &ThumbOf { scrollbar }) = q_thumb.get_mut(thumb)?;
+    let (&Scrollbar { scrollable }, track_cnode, track_transform, inset) =
+        q_scrollbar.get(scrollbar)?;
+    let (
+        mut scroll_position,
+        scrollable_node,
+        scrollable_cnode,
+        virtual_content_size,
+        mut scroll_metrics,
+        mut scroll_progress,
+        wrap,
+    ) = q_scrollable.get_mut(scrollable)?;
+    let content_size = content_size(scrollable_cnode, virtual_content_size);
+    let Some(axis) = scroll_axis(scrollable_node) else {
+        return Ok(());
+    };
+
+    let max_offset = max_offset(scrollable_cnode, virtual_content_size);
+    // The thumb wraps around along with the content rather than stopping at the ends
+    if wrap.is_none() || max_offset[axis] <= 0.0 {
+        scroll_position[axis] = scroll_position[axis].clamp(0.0, max_offset[axis]);
+    }
+    let axis_progress = progress(
+        scroll_position[axis],
+        axis,
+        scrollable_cnode,
+        virtual_content_size,
+        wrap,
+    );
+    let geometry = TrackGeometry::new(track_cnode, axis, inset.0, thumb_cnode.size[axis]);
+    let start = geometry.thumb_start(axis_progress);
 
-    if scrollable_node.overflow.y == OverflowAxis::Scroll {
-        let scaled_scroll_length = scrollable_cnode.content_size.y - scrollable_cnode.size.y;
-        let scroll_length = scrollable_cnode.inverse_scale_factor * scaled_scroll_length;
-        scroll_position.y = scroll_position.y.clamp(0.0, scroll_length);
-        thumb_node.margin.top = if scroll_length <= 0.0 {
-            Val::ZERO
-        } else {
-            let ratio = scroll_position.y / scroll_length;
-            let scaled_drag_length = track_cnode.size.y
-                - (track_cnode.border.min_inset.y
-                    + track_cnode.border.max_inset.y
-                    + thumb_cnode.size.y);
-            let drag_length = track_cnode.inverse_scale_factor * scaled_drag_length;
-            Val::Px(ratio * drag_length)
-        };
-        debug!("scrollable node size: {}", scrollable_cnode.size.y);
-        debug!(
-            "scrollable content size: {}",
-            scrollable_cnode.content_size.y,
-        );
-        debug!("thumb top margin: {:?}\n", thumb_node.margin.top);
-    } else if scrollable_node.overflow.x == OverflowAxis::Scroll {
-        let scaled_scroll_length = scrollable_cnode.content_size.x - scrollable_cnode.size.x;
-        let scroll_length = scrollable_cnode.inverse_scale_factor * scaled_scroll_length;
-        scroll_position.x = scroll_position.x.clamp(0.0, scroll_length);
-        thumb_node.margin.left = if scroll_length <= 0.0 {
-            Val::ZERO
-        } else {
-            let ratio = scroll_position.x / scroll_length;
-            let scaled_drag_length = track_cnode.size.x
-                - (track_cnode.border.min_inset.x
-                    + track_cnode.border.max_inset.x
-                    + thumb_cnode.size.x);
-            let drag_length = track_cnode.inverse_scale_factor * scaled_drag_length;
-            Val::Px(ratio * drag_length)
-        };
+    // The thumb is absolutely positioned, so its offset is relative to the padding box of the track
+    let offset =
+        Val::Px(track_cnode.inverse_scale_factor * (start - track_cnode.border.min_inset[axis]));
+    match axis {
+        1 => thumb_node.top = offset,
+        _ => thumb_node.left = offset,
     }
+    // Center of the thumb in physical pixels
+    let center = track_transform.translation[axis] - track_cnode.size[axis] / 2.0
+        + start
+        + thumb_cnode.size[axis] / 2.0;
+    place_node(thumb, axis, center, &mut q_transform, &q_children)?;
+
+    let inverse_scale_factor = scrollable_cnode.inverse_scale_factor;
+    let mut progress = Vec2::select(
+        max_offset.cmpgt(Vec2::ZERO),
+        (scroll_position.0 / max_offset).clamp(Vec2::ZERO, Vec2::ONE),
+        Vec2::ZERO,
+    );
+    progress[axis] = axis_progress;
+    // The thumb starts past the border, padding and inset of the track across it
+    let mut thumb_min = Vec2::splat(inset.0)
+        + track_cnode.inverse_scale_factor * track_cnode.content_inset().min_inset;
+    thumb_min[axis] = track_cnode.inverse_scale_factor * start;
+    let thumb_rect = Rect::from_corners(
+        thumb_min,
+        thumb_min + track_cnode.inverse_scale_factor * thumb_cnode.size,
+    );
+    scroll_metrics.set_if_neq(ScrollMetrics {
+        viewport_size: inverse_scale_factor * scrollable_cnode.size,
+        content_size: inverse_scale_factor * content_size,
+        max_offset,
+        progress,
+        thumb_rect,
+        overflowing: thumb_ratio(scrollable_cnode.size[axis], content_size[axis]) < 1.0,
+    });
+    scroll_progress.set_if_neq(ScrollProgress(progress[axis]));
+    debug!("scrollable node size: {}", scrollable_cnode.size[axis]);
+    debug!("scrollable content size: {}", content_size[axis]);
+    debug!("thumb offset: {offset:?}\n");
+    Ok(())
+}
+
+/// Moves a node and its descendants along `axis` so the node is centered on `center`, in physical pixels.
+///
+/// This is where the next layout will put the node, e.g. the thumb. Moving it right away spares us drawing it a frame late. Descendants the query cannot move, e.g. nested scrollbars, are left along with their own descendants for the next layout to place.
+fn place_node(
+    node: Entity,
+    axis: usize,
+    center: f32,
+    q_transform: &mut Query<&mut UiGlobalTransform, (Without<Scrollbar>, Without<Scrollable>)>,
+    q_children: &Query<&Children>,
+) -> Result {
+    let shift = center - q_transform.get(node)?.translation[axis];
+    shift_node(node, axis, shift, q_transform, q_children);
     Ok(())
 }
+
+/// Moves a node and its descendants along `axis` by `shift` physical pixels, skipping the nodes `q_transform` does not match.
+fn shift_node(
+    node: Entity,
+    axis: usize,
+    shift: f32,
+    q_transform: &mut Query<&mut UiGlobalTransform, (Without<Scrollbar>, Without<Scrollable>)>,
+    q_children: &Query<&Children>,
+) {
+    let Ok(mut transform) = q_transform.get_mut(node) else {
+        return;
+    };
+    let mut affine = **transform;
+    affine.translation[axis] += shift;
+    *transform = affine.into();
+    for &child in q_children.get(node).into_iter().flatten() {
+        shift_node(child, axis, shift, q_transform, q_children);
+    }
+}
@@ -0,0 +1,152 @@
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::{
+    VirtualContentSize,
+    scrollable::{max_offset, progress},
+};
+
+/// Component of a scrolled node sharing its [`ScrollPosition`] with other nodes on one or both axes.
+///
+/// Nodes whose [`ScrollLink`] names the same group entity on an axis form a group on that axis. When the [`ScrollPosition`] of a member changes along that axis, e.g. because the user scrolled it, the other members are scrolled along, which suits diff views, line number gutters and table headers. The group entity is only an identifier: any entity does, such as a common ancestor of the members.
+///
+/// The members need not be [`Scrollable`](crate::Scrollable) nodes: any node with [`ScrollPosition`] can be linked, including nodes without a scrollbar. A member joining a group keeps its offset until another member is scrolled.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_scrollbar::ScrollLink;
+/// fn setup(mut commands: Commands) {
+///     let table = commands.spawn(Node::default()).id();
+///     // The header follows the body horizontally and the gutter follows it vertically
+///     commands.spawn((Node::default(), ScrollLink::horizontal(table)));
+///     commands.spawn((Node::default(), ScrollLink::vertical(table)));
+///     commands.spawn((Node::default(), ScrollLink::horizontal(table).with_vertical(table)));
+/// }
+/// ```
+#[derive(Component, Default, Copy, Clone, Reflect, Debug)]
+#[require(ScrollPosition, LinkState)]
+pub struct ScrollLink {
+    /// Group shared on the horizontal axis, if any.
+    pub x: Option<Entity>,
+    /// Group shared on the vertical axis, if any.
+    pub y: Option<Entity>,
+    /// How the offset of the scrolled member is carried over to this node.
+    pub mode: ScrollLinkMode,
+}
+
+impl ScrollLink {
+    /// Creates a [`ScrollLink`] sharing the horizontal offset with `group`.
+    pub fn horizontal(group: Entity) -> Self {
+        Self {
+            x: Some(group),
+            ..default()
+        }
+    }
+
+    /// Creates a [`ScrollLink`] sharing the vertical offset with `group`.
+    pub fn vertical(group: Entity) -> Self {
+        Self {
+            y: Some(group),
+            ..default()
+        }
+    }
+
+    /// Also shares the horizontal offset with `group`.
+    pub fn with_horizontal(mut self, group: Entity) -> Self {
+        self.x = Some(group);
+        self
+    }
+
+    /// Also shares the vertical offset with `group`.
+    pub fn with_vertical(mut self, group: Entity) -> Self {
+        self.y = Some(group);
+        self
+    }
+
+    /// Sets how the offset of the scrolled member is carried over to this node.
+    pub fn with_mode(mut self, mode: ScrollLinkMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+/// How a [`ScrollLink`] node follows the scrolled member of its group.
+#[derive(Default, Copy, Clone, PartialEq, Eq, Reflect, Debug)]
+pub enum ScrollLinkMode {
+    /// The node scrolls to the same offset in logical pixels, as far as its content allows.
+    #[default]
+    Pixels,
+    /// The node scrolls to the same fraction of its largest offset, whatever the size of its content.
+    Progress,
+}
+
+/// Component of a [`ScrollLink`] node tracking the offset it was last synchronized at.
+#[derive(Component, Default, Copy, Clone, Debug)]
+pub(crate) struct LinkState {
+    /// Scroll position observed after the previous synchronization, if any.
+    last_position: Option<Vec2>,
+}
+
+/// Offset of the member scrolled on an axis of a group, in logical pixels and as a fraction of its largest offset.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Source {
+    entity: Entity,
+    position: f32,
+    progress: f32,
+}
+
+/// Copies the [`ScrollPosition`] of the scrolled member of each [`ScrollLink`] group to the other members.
+///
+/// A member is considered scrolled when its position differs from the one it was last synchronized at, so the positions written by this system do not feed back into it. If several members of a group were scrolled since the last synchronization, one of them wins. This system runs in `PostUpdate` before `UiSystems::Layout` so the whole group is laid out in the same frame. Largest offsets are computed from the layout of the previous frame.
+pub(crate) fn link_scroll_positions(
+    mut q_link: Query<(
+        Entity,
+        &ScrollLink,
+        &mut LinkState,
+        &mut ScrollPosition,
+        &ComputedNode,
+        Option<&VirtualContentSize>,
+    )>,
+    mut sources: Local<HashMap<(Entity, usize), Source>>,
+) {
+    sources.clear();
+    for (entity, link, state, scroll_position, cnode, virtual_content_size) in &q_link {
+        let max_offset = max_offset(cnode, virtual_content_size);
+        for (axis, group) in [(0, link.x), (1, link.y)] {
+            let Some(group) = group else {
+                continue;
+            };
+            // Bevy clamps the position to the content only when laying it out
+            let position = scroll_position[axis].clamp(0.0, max_offset[axis]);
+            if state
+                .last_position
+                .is_some_and(|last_position| scroll_position[axis] != last_position[axis])
+            {
+                sources.entry((group, axis)).or_insert(Source {
+                    entity,
+                    position,
+                    progress: progress(position, axis, cnode, virtual_content_size, None),
+                });
+            }
+        }
+    }
+
+    for (entity, link, mut state, mut scroll_position, cnode, virtual_content_size) in &mut q_link {
+        let max_offset = max_offset(cnode, virtual_content_size);
+        for (axis, group) in [(0, link.x), (1, link.y)] {
+            let Some(source) = group.and_then(|group| sources.get(&(group, axis))) else {
+                continue;
+            };
+            if source.entity == entity {
+                continue;
+            }
+            let position = match link.mode {
+                ScrollLinkMode::Pixels => source.position.clamp(0.0, max_offset[axis]),
+                ScrollLinkMode::Progress => source.progress * max_offset[axis],
+            };
+            if scroll_position[axis] != position {
+                scroll_position[axis] = position;
+            }
+        }
+        state.last_position = Some(scroll_position.0);
+    }
+}
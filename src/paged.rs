@@ -0,0 +1,335 @@
+use bevy::{picking::pointer::PointerPress, prelude::*};
+
+use crate::{VirtualContentSize, scrollable::max_offset};
+
+/// Component of a [`Scrollable`](crate::Scrollable) node scrolling its content one page at a time, a page being one viewport long.
+///
+/// Each notch of a mouse wheel moves the content by exactly one page, and so do half a page of pixel scrolls, e.g. from a touchpad, or of thumb drag. Scrolls are ignored while a page is turning. With the `gamepad` feature, flicking the left stick of a gamepad along the scrolling axis moves it by one page too. The content then animates to the start of that page. The last page ends with the content, so it may overlap the previous one.
+///
+/// Clicking the trough of the [`Scrollbar`](crate::Scrollbar) or writing [`ScrollProgress`](crate::ScrollProgress) animates the content to the page closest to the requested position.
+///
+/// Add a [`PageIndicator`] to show the pages as dots, along with the [`Scrollbar`](crate::Scrollbar) or instead of it, e.g. by hiding the [`Scrollbar`](crate::Scrollbar).
+#[derive(Component, Copy, Clone, Reflect, Debug)]
+#[require(PageState)]
+pub struct Paged {
+    /// Rate of the page turning animation. Higher is faster.
+    pub speed: f32,
+    /// Whether the left stick of gamepads turns the pages. Requires the `gamepad` feature.
+    pub stick: bool,
+}
+
+impl Default for Paged {
+    fn default() -> Self {
+        Self {
+            speed: 15.0,
+            stick: true,
+        }
+    }
+}
+
+impl Paged {
+    /// Sets the rate of the page turning animation.
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Sets whether the left stick of gamepads turns the pages.
+    pub fn with_stick(mut self, stick: bool) -> Self {
+        self.stick = stick;
+        self
+    }
+}
+
+/// Component of a [`Paged`] node tracking its page turning.
+#[derive(Component, Default, Copy, Clone, Debug)]
+pub(crate) struct PageState {
+    /// Number of pages to turn, negative to go back.
+    pub(crate) turns: i32,
+    /// Page to jump to.
+    pub(crate) jump: Option<usize>,
+    /// Page the content is animating to.
+    target: Option<usize>,
+    /// Drag of the thumb in logical pixels of content not turned into pages yet.
+    pub(crate) drag: f32,
+    /// Pixel mouse scrolls in logical pixels of content not turned into pages yet.
+    pub(crate) wheel: f32,
+    /// Whether the stick went back to rest since it last turned a page.
+    #[cfg(feature = "gamepad")]
+    stick_at_rest: bool,
+}
+
+impl PageState {
+    /// Returns `true` while a page is turning or about to.
+    pub(crate) fn is_turning(&self) -> bool {
+        self.target.is_some() || self.jump.is_some() || self.turns != 0
+    }
+}
+
+/// Component of a `Node` showing the pages of a [`Paged`] node as a row of dots.
+///
+/// The dots are spawned as children of this node by [`ScrollbarPreLayoutSystems`](crate::ScrollbarPreLayoutSystems), one per page. The dot of the current page is highlighted and clicking a dot scrolls to its page.
+#[derive(Component, Copy, Clone, Reflect, Debug)]
+#[require(Node)]
+pub struct PageIndicator {
+    /// The [`Paged`] node whose pages are shown.
+    pub scrollable: Entity,
+    /// Diameter of the dots in logical pixels.
+    pub dot_size: f32,
+    /// Color of the dots.
+    pub color: Color,
+    /// Color of the dot of the current page.
+    pub active_color: Color,
+}
+
+impl PageIndicator {
+    /// Creates a [`PageIndicator`] of the pages of `scrollable`.
+    pub fn new(scrollable: Entity) -> Self {
+        Self {
+            scrollable,
+            dot_size: 8.0,
+            color: Color::srgb(0.5, 0.5, 0.5),
+            active_color: Color::WHITE,
+        }
+    }
+
+    /// Sets the diameter of the dots.
+    pub fn with_dot_size(mut self, dot_size: f32) -> Self {
+        self.dot_size = dot_size;
+        self
+    }
+
+    /// Sets the colors of the dots and of the dot of the current page.
+    pub fn with_colors(mut self, color: Color, active_color: Color) -> Self {
+        self.color = color;
+        self.active_color = active_color;
+        self
+    }
+}
+
+/// Component of a dot of a [`PageIndicator`].
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct PageDot {
+    page: usize,
+}
+
+/// Pages of a [`Paged`] node, in logical pixels.
+pub(crate) struct Pages {
+    length: f32,
+    max_offset: f32,
+    count: usize,
+}
+
+impl Pages {
+    pub(crate) fn new(
+        cnode: &ComputedNode,
+        virtual_content_size: Option<&VirtualContentSize>,
+        axis: usize,
+    ) -> Self {
+        let length = cnode.inverse_scale_factor * cnode.size[axis];
+        let max_offset = max_offset(cnode, virtual_content_size)[axis];
+        let count = if length > 0.0 {
+            1 + (max_offset / length - 0.01).ceil().max(0.0) as usize
+        } else {
+            1
+        };
+        Self {
+            length,
+            max_offset,
+            count,
+        }
+    }
+
+    /// Returns the scroll position of the start of `page`.
+    fn position(&self, page: usize) -> f32 {
+        (page as f32 * self.length).min(self.max_offset)
+    }
+
+    /// Returns the page closest to the scroll position `position`.
+    pub(crate) fn page_at(&self, position: f32) -> usize {
+        if position >= self.max_offset - 0.5 {
+            self.count - 1
+        } else if self.length > 0.0 {
+            ((position / self.length).round() as usize).min(self.count - 1)
+        } else {
+            0
+        }
+    }
+}
+
+/// Turns the pages of [`Paged`] nodes with the left stick of gamepads.
+#[cfg(feature = "gamepad")]
+pub(crate) fn turn_pages_with_sticks(
+    mut q_paged: Query<(&Paged, &mut PageState, &Node)>,
+    q_gamepad: Query<&Gamepad>,
+) {
+    for (paged, mut state, node) in &mut q_paged {
+        if !paged.stick {
+            continue;
+        }
+        let Some(axis) = crate::scroll_axis(node) else {
+            continue;
+        };
+        // The stick points up to go back on vertical nodes
+        let tilt = q_gamepad
+            .iter()
+            .map(|gamepad| gamepad.left_stick() * Vec2::new(1.0, -1.0))
+            .map(|stick| stick[axis])
+            .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+            .unwrap_or_default();
+        if tilt.abs() < 0.25 {
+            state.stick_at_rest = true;
+        } else if tilt.abs() > 0.5 && state.stick_at_rest {
+            state.stick_at_rest = false;
+            state.turns += tilt.signum() as i32;
+        }
+    }
+}
+
+/// Animates the [`ScrollPosition`] of [`Paged`] nodes to the page they were turned to.
+///
+/// This system runs in `PostUpdate` before `UiSystems::Layout` so the animated position is laid out in the same frame. Pages are computed from the layout of the previous frame.
+pub(crate) fn turn_pages(
+    mut q_paged: Query<(
+        &Paged,
+        &mut PageState,
+        &mut ScrollPosition,
+        &Node,
+        &ComputedNode,
+        Option<&VirtualContentSize>,
+    )>,
+    q_pointer_press: Query<&PointerPress>,
+    time: Res<Time>,
+) {
+    let pressed = q_pointer_press.iter().any(PointerPress::is_any_pressed);
+    for (paged, mut state, mut scroll_position, node, cnode, virtual_content_size) in &mut q_paged {
+        let Some(axis) = crate::scroll_axis(node) else {
+            continue;
+        };
+        let pages = Pages::new(cnode, virtual_content_size, axis);
+        let position = scroll_position[axis];
+
+        // Half a page of thumb drag turns a page, and the drag starts adding up again
+        if state.drag.abs() >= pages.length / 2.0 || !pressed {
+            if pages.length > 0.0 && state.drag.abs() >= pages.length / 2.0 {
+                state.turns += state.drag.signum() as i32;
+            }
+            state.drag = 0.0;
+        }
+        // Half a page of pixel mouse scrolls turns a page too
+        if pages.length > 0.0 && state.wheel.abs() >= pages.length / 2.0 {
+            state.turns += state.wheel.signum() as i32;
+            state.wheel = 0.0;
+        }
+
+        if let Some(page) = state.jump.take() {
+            state.target = Some(page.min(pages.count - 1));
+        }
+        if state.turns != 0 {
+            let page = state.target.unwrap_or_else(|| pages.page_at(position)) as i32;
+            let last_page = pages.count as i32 - 1;
+            state.target = Some((page + state.turns).clamp(0, last_page) as usize);
+            state.turns = 0;
+        }
+        let Some(page) = state.target else {
+            continue;
+        };
+
+        let target = pages.position(page);
+        if (target - position).abs() <= 0.5 {
+            state.target = None;
+            scroll_position[axis] = target;
+        } else {
+            scroll_position[axis] =
+                position + (target - position) * (1.0 - (-paged.speed * time.delta_secs()).exp());
+        }
+    }
+}
+
+/// Spawns the dots of [`PageIndicator`]s and highlights the dot of the current page.
+///
+/// This system runs in `PostUpdate` before `UiSystems::Layout`, after the pages are turned.
+pub(crate) fn update_page_indicators(
+    q_indicator: Query<(Entity, &PageIndicator, Option<&Children>)>,
+    q_paged: Query<
+        (
+            &ScrollPosition,
+            &Node,
+            &ComputedNode,
+            Option<&VirtualContentSize>,
+        ),
+        With<Paged>,
+    >,
+    mut q_dot: Query<(&PageDot, &mut BackgroundColor)>,
+    mut commands: Commands,
+) {
+    for (indicator_entity, indicator, children) in &q_indicator {
+        let Ok((scroll_position, node, cnode, virtual_content_size)) =
+            q_paged.get(indicator.scrollable)
+        else {
+            continue;
+        };
+        let Some(axis) = crate::scroll_axis(node) else {
+            continue;
+        };
+        let pages = Pages::new(cnode, virtual_content_size, axis);
+        let current_page = pages.page_at(scroll_position[axis]);
+
+        let dots = children
+            .into_iter()
+            .flatten()
+            .filter(|&&child| q_dot.contains(child))
+            .copied()
+            .collect::<Vec<_>>();
+        if dots.len() != pages.count {
+            for dot in dots {
+                commands.entity(dot).despawn();
+            }
+            for page in 0..pages.count {
+                let color = if page == current_page {
+                    indicator.active_color
+                } else {
+                    indicator.color
+                };
+                commands
+                    .spawn((
+                        PageDot { page },
+                        Node {
+                            width: Val::Px(indicator.dot_size),
+                            height: Val::Px(indicator.dot_size),
+                            margin: UiRect::all(Val::Px(indicator.dot_size / 4.0)),
+                            border_radius: BorderRadius::MAX,
+                            ..default()
+                        },
+                        BackgroundColor(color),
+                        ChildOf(indicator_entity),
+                    ))
+                    .observe(jump_to_page_on_dot_click);
+            }
+            continue;
+        }
+
+        for dot in dots {
+            let (&PageDot { page }, mut background_color) = q_dot.get_mut(dot).unwrap();
+            let color = if page == current_page {
+                indicator.active_color
+            } else {
+                indicator.color
+            };
+            background_color.set_if_neq(BackgroundColor(color));
+        }
+    }
+}
+
+/// Observer watching the dots of a [`PageIndicator`] for `Click` triggers.
+fn jump_to_page_on_dot_click(
+    click: On<Pointer<Click>>,
+    q_dot: Query<(&PageDot, &ChildOf)>,
+    q_indicator: Query<&PageIndicator>,
+    mut q_state: Query<&mut PageState>,
+) -> Result {
+    let (&PageDot { page }, child_of) = q_dot.get(click.entity)?;
+    let indicator = q_indicator.get(child_of.parent())?;
+    q_state.get_mut(indicator.scrollable)?.jump = Some(page);
+    Ok(())
+}
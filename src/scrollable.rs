@@ -1,6 +1,9 @@
-use bevy::{prelude::*, text::LineHeight};
+use bevy::{input::mouse::MouseScrollUnit, prelude::*, text::LineHeight};
 
-use crate::Scrollbar;
+use crate::{
+    Scrollbar, WrapAround,
+    paged::{PageState, Pages},
+};
 
 /// Component of a `Node` with overflowing content and linked to a [`Scrollbar`].
 ///
@@ -11,7 +14,14 @@ use crate::Scrollbar;
 /// * or inserted via `SpawnRelated::spawn_one` (see [example 2](crate#example-2)).
 #[derive(Component, Clone, Reflect, Debug)]
 #[relationship_target(relationship = Scrollbar, linked_spawn)]
-#[require(Node, ScrollSpeed)]
+#[require(
+    Node,
+    ScrollSpeed,
+    ScrollMetrics,
+    ScrollProgress,
+    LastContentSize,
+    WheelStreak
+)]
 pub struct Scrollable {
     /// The [`Scrollbar`] entity of this scrollable entity.
     scrollbar: Entity,
@@ -24,8 +34,152 @@ impl Scrollable {
     }
 }
 
+/// Component of a [`Scrollable`] node holding the size of its content in physical pixels on the last update of its thumb.
+///
+/// The layout updates the content size without triggering change detection, so it is compared against this one instead.
+#[derive(Component, Default, Copy, Clone, PartialEq, Debug)]
+pub(crate) struct LastContentSize(pub(crate) Vec2);
+
+/// Component of a [`Scrollable`] node exposing its scroll geometry, so UI code need not derive it from `ComputedNode`.
+///
+/// This component is maintained by [`ScrollbarSystems`](crate::ScrollbarSystems) whenever the thumb is updated. Sizes and offsets are in logical pixels, like [`ScrollPosition`].
+///
+/// Content that is empty, zero-sized or fits in the node does not overflow it: it cannot be scrolled and the thumb fills the whole track. Check [`is_overflowing`](Self::is_overflowing) if you would rather hide the [`Scrollbar`] in that case.
+#[derive(Component, Default, Copy, Clone, PartialEq, Reflect, Debug)]
+pub struct ScrollMetrics {
+    pub(crate) viewport_size: Vec2,
+    pub(crate) content_size: Vec2,
+    pub(crate) max_offset: Vec2,
+    pub(crate) progress: Vec2,
+    pub(crate) thumb_rect: Rect,
+    pub(crate) overflowing: bool,
+}
+
+impl ScrollMetrics {
+    /// Returns the size of the node through which the content is seen.
+    pub fn viewport_size(&self) -> Vec2 {
+        self.viewport_size
+    }
+
+    /// Returns the size of the content, or the [`VirtualContentSize`] of the node if it has one.
+    pub fn content_size(&self) -> Vec2 {
+        self.content_size
+    }
+
+    /// Returns the largest [`ScrollPosition`] of the node on each axis, zero where the content does not overflow it.
+    pub fn max_offset(&self) -> Vec2 {
+        self.max_offset
+    }
+
+    /// Returns the [`ScrollPosition`] of the node as a fraction of [`max_offset`](Self::max_offset) on each axis, zero where the content does not overflow it.
+    pub fn progress(&self) -> Vec2 {
+        self.progress
+    }
+
+    /// Returns the rectangle of the thumb relative to the top-left corner of the track.
+    pub fn thumb_rect(&self) -> Rect {
+        self.thumb_rect
+    }
+
+    /// Returns `true` if the content overflows the node along its scrolling axis.
+    pub fn is_overflowing(&self) -> bool {
+        self.overflowing
+    }
+}
+
+/// Component of a [`Scrollable`] node holding its scroll position along its scrolling axis as a fraction of the largest one, from `0.0` to `1.0`.
+///
+/// This component is the writable counterpart of [`ScrollMetrics::progress`] along the scrolling axis, and is updated along with it by [`ScrollbarSystems`](crate::ScrollbarSystems). Writing it scrolls the content to that fraction at the next layout, which is handy to save and restore a scroll position or to link widgets regardless of their content sizes. A fraction written before the node is laid out, e.g. when spawning it, is kept until its content overflows it. Content that does not overflow the node stays at `0.0`.
+#[derive(Component, Default, Copy, Clone, PartialEq, Reflect, Debug)]
+#[require(PendingProgress)]
+pub struct ScrollProgress(pub f32);
+
+/// Component of a [`Scrollable`] node holding the [`ScrollProgress`] written to it until its content can be scrolled there.
+#[derive(Component, Default, Copy, Clone, Debug)]
+pub(crate) struct PendingProgress(Option<f32>);
+
+/// Scrolls the content of [`Scrollable`] nodes whose [`ScrollProgress`] was written since the last update of their thumb.
+///
+/// The content is scrolled once its layout overflows the node, so a progress written before the node is laid out is not lost.
+pub(crate) fn scroll_to_progress(
+    mut q_scrollable: Query<
+        (
+            &mut ScrollPosition,
+            &Node,
+            &ScrollMetrics,
+            Ref<ScrollProgress>,
+            &mut PendingProgress,
+            &ComputedNode,
+            Option<&VirtualContentSize>,
+            Option<&WrapAround>,
+            Option<&mut PageState>,
+        ),
+        With<Scrollable>,
+    >,
+) {
+    for (
+        mut scroll_position,
+        node,
+        metrics,
+        progress,
+        mut pending,
+        cnode,
+        virtual_content_size,
+        wrap,
+        page_state,
+    ) in &mut q_scrollable
+    {
+        let Some(axis) = crate::scroll_axis(node) else {
+            continue;
+        };
+        // The progress written back by the plugin matches the metrics, so only a user write moves the content
+        if progress.is_changed() && progress.0 != metrics.progress[axis] {
+            pending.0 = Some(progress.0.clamp(0.0, 1.0));
+        }
+        let max_offset = max_offset(cnode, virtual_content_size)[axis];
+        if max_offset <= 0.0 {
+            continue;
+        }
+        let Some(progress) = pending.0.take() else {
+            continue;
+        };
+
+        let position = match wrap {
+            // The progress of content wrapping around is a fraction of a turn
+            Some(wrap) => {
+                cnode.inverse_scale_factor
+                    * progress
+                    * content_size(cnode, virtual_content_size)[axis]
+                    - wrap.offset()
+            }
+            None => progress * max_offset,
+        };
+        // Paged content animates to the closest page instead
+        match page_state {
+            Some(mut page_state) => {
+                let pages = Pages::new(cnode, virtual_content_size, axis);
+                page_state.jump = Some(pages.page_at(position));
+            }
+            None => scroll_position[axis] = position,
+        }
+    }
+}
+
+/// Returns the length of the thumb as a fraction of the track, given the sizes of the [`Scrollable`] node and of its content along the scrolling axis.
+///
+/// The thumb fills the track when the content does not overflow the node, including when either is zero-sized.
+pub(crate) fn thumb_ratio(size: f32, content_size: f32) -> f32 {
+    if size > 0.0 && content_size > size {
+        size / content_size
+    } else {
+        1.0
+    }
+}
+
 /// Component of a [`Scrollable`] node configuring how fast its content scrolls when scrolling the mouse.
 ///
+/// Notched mouse wheels usually report `MouseScrollUnit::Line` scrolls while touchpads and high-resolution wheels report `MouseScrollUnit::Pixel` scrolls. To tune one unit without the other, add [`ScrollSpeedPerUnit`] to the node. Consecutive line scrolls can also be accelerated by adding [`WheelAcceleration`] to the node.
+///
 /// This is unrelated to how fast the content scrolls when dragging the thumb of the [`Scrollbar`]. See [`DragSpeed`](super::DragSpeed) for that.
 #[derive(Component, Copy, Clone, Reflect, Debug)]
 pub struct ScrollSpeed(pub f32);
@@ -41,6 +195,159 @@ impl ScrollSpeed {
     pub const DEFAULT: f32 = 1.0;
 }
 
+/// Component of a [`Scrollable`] node overriding its [`ScrollSpeed`] for one or both units of mouse scrolls.
+///
+/// A unit without an override scrolls at the [`ScrollSpeed`] of the node.
+#[derive(Component, Default, Copy, Clone, Reflect, Debug)]
+pub struct ScrollSpeedPerUnit {
+    /// Multiplier of `MouseScrollUnit::Line` scrolls, if overridden.
+    pub line: Option<f32>,
+    /// Multiplier of `MouseScrollUnit::Pixel` scrolls, if overridden.
+    pub pixel: Option<f32>,
+}
+
+impl ScrollSpeedPerUnit {
+    /// Overrides the multiplier of `MouseScrollUnit::Line` scrolls.
+    pub const fn with_line(mut self, line: f32) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Overrides the multiplier of `MouseScrollUnit::Pixel` scrolls.
+    pub const fn with_pixel(mut self, pixel: f32) -> Self {
+        self.pixel = Some(pixel);
+        self
+    }
+
+    /// Returns the multiplier of scrolls in `unit`, or `speed` if it is not overridden.
+    pub fn of(&self, unit: MouseScrollUnit, speed: &ScrollSpeed) -> f32 {
+        match unit {
+            MouseScrollUnit::Line => self.line,
+            MouseScrollUnit::Pixel => self.pixel,
+        }
+        .unwrap_or(speed.0)
+    }
+}
+
+/// Component of a [`Scrollable`] node accelerating its consecutive line scrolls.
+///
+/// Each line scroll following the previous one within `interval` seconds raises the multiplier of the [`ScrollSpeed`] by `step`, up to `max`. The multiplier goes back to 1.0 as soon as the wheel rests for longer than `interval`.
+#[derive(Component, Copy, Clone, Reflect, Debug)]
+pub struct WheelAcceleration {
+    /// Maximum delay in seconds between two line scrolls for them to be consecutive.
+    pub interval: f32,
+    /// Increase of the multiplier per consecutive line scroll.
+    pub step: f32,
+    /// Maximum multiplier.
+    pub max: f32,
+}
+
+impl Default for WheelAcceleration {
+    fn default() -> Self {
+        Self {
+            interval: 0.1,
+            step: 0.5,
+            max: 5.0,
+        }
+    }
+}
+
+impl WheelAcceleration {
+    /// Returns the multiplier applied to a line scroll preceded by `streak` consecutive line scrolls.
+    pub fn multiplier(&self, streak: u32) -> f32 {
+        (1.0 + self.step * streak as f32).min(self.max).max(1.0)
+    }
+}
+
+/// Component of a [`Scrollable`] node configuring the direction in which mouse scrolls move its content.
+///
+/// This type is also a `Resource` configuring every [`Scrollable`] node without the component. [`ScrollbarPlugin`](crate::ScrollbarPlugin) initializes it to its default, which matches the behavior of previous versions.
+#[derive(Component, Resource, Copy, Clone, Reflect, Debug)]
+pub struct WheelDirection {
+    /// Scrolls the content the opposite way.
+    pub inverted: bool,
+    /// Scrolls horizontal content with vertical mouse scrolls. If `false`, horizontal content only responds to horizontal mouse scrolls.
+    pub vertical_to_horizontal: bool,
+}
+
+impl Default for WheelDirection {
+    fn default() -> Self {
+        Self {
+            inverted: false,
+            vertical_to_horizontal: true,
+        }
+    }
+}
+
+/// Component of a [`Scrollable`] node overriding the size of its content computed by the layout.
+///
+/// Use this component for virtualized or procedurally generated content, where only part of the content is laid out at a time. The size is given in logical pixels and replaces `ComputedNode::content_size` to size the thumb, clamp the [`ScrollPosition`], map clicks on the trough and scale thumb drags (by the ratio of the virtual size to the laid out size).
+///
+/// [`ScrollPosition`] then holds the virtual offset of the viewport in the content, which your code reads to decide what to lay out. Bevy clamps the offset it applies to the laid out content, so content no larger than the viewport is not moved by the layout.
+#[derive(Component, Copy, Clone, PartialEq, Reflect, Debug)]
+pub struct VirtualContentSize(pub Vec2);
+
+/// Returns the size of the content of a [`Scrollable`] node in physical pixels, taking [`VirtualContentSize`] into account.
+pub(crate) fn content_size(
+    cnode: &ComputedNode,
+    virtual_content_size: Option<&VirtualContentSize>,
+) -> Vec2 {
+    virtual_content_size.map_or(cnode.content_size, |size| {
+        size.0 / cnode.inverse_scale_factor
+    })
+}
+
+/// Returns the largest [`ScrollPosition`] of a [`Scrollable`] node in logical pixels on each axis, zero where the content does not overflow it.
+pub(crate) fn max_offset(
+    cnode: &ComputedNode,
+    virtual_content_size: Option<&VirtualContentSize>,
+) -> Vec2 {
+    cnode.inverse_scale_factor
+        * (content_size(cnode, virtual_content_size) - cnode.size).max(Vec2::ZERO)
+}
+
+/// Returns the progress along `axis` of a [`Scrollable`] node scrolled to `position`, from `0.0` to `1.0`.
+///
+/// The progress of content wrapping around is a fraction of a turn. Content that does not overflow the node stays at `0.0`.
+pub(crate) fn progress(
+    position: f32,
+    axis: usize,
+    cnode: &ComputedNode,
+    virtual_content_size: Option<&VirtualContentSize>,
+    wrap: Option<&WrapAround>,
+) -> f32 {
+    let max_offset = max_offset(cnode, virtual_content_size)[axis];
+    if max_offset <= 0.0 {
+        0.0
+    } else if let Some(wrap) = wrap {
+        let period = cnode.inverse_scale_factor * content_size(cnode, virtual_content_size)[axis];
+        wrap.progress(position, period)
+    } else {
+        (position / max_offset).clamp(0.0, 1.0)
+    }
+}
+
+/// Component of a [`Scrollable`] node tracking its consecutive line scrolls for [`WheelAcceleration`].
+#[derive(Component, Default, Copy, Clone, Debug)]
+pub(crate) struct WheelStreak {
+    /// Time in seconds of the last line scroll, if any.
+    last: Option<f32>,
+    /// Number of consecutive line scrolls preceding the last one.
+    count: u32,
+}
+
+impl WheelStreak {
+    /// Records a line scroll happening at `now` and returns its acceleration multiplier.
+    pub(crate) fn accelerate(&mut self, now: f32, acceleration: &WheelAcceleration) -> f32 {
+        self.count = match self.last {
+            Some(last) if now - last <= acceleration.interval => self.count + 1,
+            _ => 0,
+        };
+        self.last = Some(now);
+        acceleration.multiplier(self.count)
+    }
+}
+
 /// Component of a [`Scrollable`] node used to compute line height for mouse scroll.
 ///
 /// Only used by vertical [`Scrollbar`]s using `MouseScrollUnit::Line`.
@@ -2,10 +2,18 @@ use bevy::{
     ecs::{lifecycle::HookContext, world::DeferredWorld},
     input::mouse::MouseScrollUnit,
     prelude::*,
+    sprite::TextureSlicer,
+    ui::widget::NodeImageMode,
 };
 use log::{debug, warn};
 
-use crate::{ScrollSpeed, Scrollable, ScrollableLineHeight};
+use crate::{
+    ScrollMetrics, ScrollProgress, ScrollSpeed, ScrollSpeedPerUnit, Scrollable,
+    ScrollableLineHeight, Thumb, ThumbOf, VirtualContentSize, WheelAcceleration, WheelDirection,
+    WrapAround,
+    paged::{PageState, Pages},
+    scrollable::{LastContentSize, PendingProgress, WheelStreak, content_size, max_offset},
+};
 
 /// Component of a scrollbar `Node`.
 ///
@@ -13,18 +21,27 @@ use crate::{ScrollSpeed, Scrollable, ScrollableLineHeight};
 /// * add the `Node` component if it's not already present;
 /// * add a `Relationship` between the scrollbar and the `scrollable` entity, inserting [`Scrollable`] into the target which typically has overflowing content;
 /// * if the target does not have either `Node::overflow::y` or `Node::overflow::x` set to `OverflowAxis::Scroll`, then set `Node::overflow::y` to `OverflowAxis::Scroll` for a vertical scrollbar;
-/// * spawn the _thumb_ of the scrollbar as its child;
+/// * spawn the _thumb_ of the scrollbar as its child, unless a custom thumb is supplied through [`ThumbOf`];
 /// * spawn an observer watching the target for `Scroll` triggers;
 /// * spawn an observer watching the thumb for `Drag` triggers;
 /// * spawn an observer watching the scrollbar for `Click` triggers.
 ///
-/// The scroll speed of the content can be configured by adding [`ScrollSpeed`] to the target. The color and drag speed of the thumb can be configured by adding [`ThumbColor`] and [`DragSpeed`] to the scrollbar.
+/// Removing this component, or despawning the scrollbar, despawns the thumb and these observers, so scrollbars can be toggled at runtime.
+///
+/// This component is immutable. To point the scrollbar at another [`Scrollable`] node, e.g. to share it between tabbed panels, insert a new [`Scrollbar`] on it: the observer watching for `Scroll` triggers moves to the new node and the thumb turns along its direction, while the track, the thumb and their styling are kept.
+///
+/// The thumb also turns when the overflowing axis of the [`Scrollable`] node changes at runtime. A warning is logged if the node no longer has an axis set to `OverflowAxis::Scroll`.
+///
+/// The thumb is absolutely positioned in the track and located through the [`Thumb`] relationship, so the track can host other children such as tick marks, labels or stepper buttons. Clicks on those children are not trough clicks and are left to you.
+///
+/// The scroll speed of the content can be configured by adding [`ScrollSpeed`] to the target. The color, look and drag speed of the thumb can be configured by adding [`ThumbColor`], [`ThumbRadius`], [`ThumbInset`], [`ThumbStyle`] and [`DragSpeed`] to the scrollbar.
 
 #[derive(Component, Clone, Reflect, Debug)]
 #[relationship(relationship_target = Scrollable)]
-#[require(Node, ThumbColor, DragSpeed)]
+#[require(Node, ThumbColor, ThumbInset, ThumbStyle, DragSpeed, ThumbCrossOffsets)]
 #[component(immutable)]
 #[component(on_add = spawn_thumb_and_observers)]
+#[component(on_remove = despawn_thumb_and_observers)]
 pub struct Scrollbar {
     /// The [`Scrollable`] entity of this scrollbar entity.
     pub scrollable: Entity,
@@ -32,11 +49,105 @@ pub struct Scrollbar {
 
 /// Component of a [`Scrollbar`] configuring the color of its thumb.
 ///
-/// This component is immutable to remind you it is only used at the spawning of the [`Scrollbar`]. If you want to change the color of the thumb afterwards, mutate its `Color` component directly.
+/// Mutating this component recolors the thumb, so themes can be switched at runtime. It fills the thumb spawned by the [`Scrollbar`], or tints its image if it has one (see [`ThumbStyle`]). The thumb supplied through [`ThumbOf`] is only recolored when this component changes after it was added.
 #[derive(Component, Default, Copy, Clone, Reflect, Debug)]
-#[component(immutable)]
 pub struct ThumbColor(pub Color);
 
+/// Component of a [`Scrollbar`] configuring the border radius of its thumb.
+///
+/// Without this component, the thumb spawned by the [`Scrollbar`] gets the border radius of the track. Mutating this component updates the thumb.
+#[derive(Component, Default, Copy, Clone, Reflect, Debug)]
+pub struct ThumbRadius(pub BorderRadius);
+
+/// Component of a [`Scrollbar`] configuring the gap in logical pixels between its thumb and the sides and ends of the track.
+///
+/// The gap is added to the border and padding of the track. The thumb travels along what is left of the track, and clicks on the track map to that same lane. Mutating this component updates the thumb. A thumb without a size across the track spans it across, minus this gap on each side.
+#[derive(Component, Default, Copy, Clone, Reflect, Debug)]
+pub struct ThumbInset(pub f32);
+
+/// Component of a [`Scrollbar`] configuring the look of its thumb beyond its color.
+///
+/// With an image, the thumb is drawn as an `ImageNode` tinted by [`ThumbColor`] instead of being filled with it. Slice the image with [`ThumbStyle::with_slicer`] so its corners and edges do not stretch with the length of the thumb. A grip icon can be centered on the thumb with [`ThumbStyle::with_grip`].
+///
+/// This component is immutable and only used at the spawning of the [`Scrollbar`]. The track itself is the [`Scrollbar`] node, so texture it by adding an `ImageNode` to it.
+#[derive(Component, Clone, Reflect, Debug)]
+#[component(immutable)]
+pub struct ThumbStyle {
+    /// Image of the thumb.
+    pub image: Option<Handle<Image>>,
+    /// How the image of the thumb is scaled to its size.
+    pub image_mode: NodeImageMode,
+    /// Border of the thumb.
+    pub border: UiRect,
+    /// Color of the border of the thumb.
+    pub border_color: BorderColor,
+    /// Outline of the thumb.
+    pub outline: Option<Outline>,
+    /// Icon centered on the thumb.
+    pub grip: Option<ThumbGrip>,
+}
+
+impl Default for ThumbStyle {
+    fn default() -> Self {
+        Self {
+            image: None,
+            image_mode: NodeImageMode::Stretch,
+            border: UiRect::DEFAULT,
+            border_color: BorderColor::DEFAULT,
+            outline: None,
+            grip: None,
+        }
+    }
+}
+
+impl ThumbStyle {
+    /// Sets the image of the thumb, stretched to its size.
+    pub fn with_image(mut self, image: Handle<Image>) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Scales the image of the thumb with nine-slicing.
+    pub fn with_slicer(mut self, slicer: TextureSlicer) -> Self {
+        self.image_mode = NodeImageMode::Sliced(slicer);
+        self
+    }
+
+    /// Sets the border of the thumb and its color.
+    pub fn with_border(mut self, border: UiRect, color: Color) -> Self {
+        self.border = border;
+        self.border_color = BorderColor::all(color);
+        self
+    }
+
+    /// Sets the outline of the thumb.
+    pub fn with_outline(mut self, outline: Outline) -> Self {
+        self.outline = Some(outline);
+        self
+    }
+
+    /// Centers an icon of `size` logical pixels on the thumb.
+    pub fn with_grip(mut self, image: Handle<Image>, size: Vec2) -> Self {
+        self.grip = Some(ThumbGrip {
+            image,
+            size,
+            color: Color::WHITE,
+        });
+        self
+    }
+}
+
+/// Icon centered on the thumb of a [`Scrollbar`]. See [`ThumbStyle::grip`].
+#[derive(Clone, Reflect, Debug)]
+pub struct ThumbGrip {
+    /// Image of the icon.
+    pub image: Handle<Image>,
+    /// Size of the icon in logical pixels.
+    pub size: Vec2,
+    /// Tint of the icon.
+    pub color: Color,
+}
+
 /// Component of a [`Scrollbar`] configuring how fast its thumb moves when dragged.
 ///
 /// This is unrelated to how fast the content scrolls when scrolling the mouse. See [`ScrollSpeed`] for that.
@@ -54,84 +165,398 @@ impl DragSpeed {
     pub const DEFAULT: f32 = 4.0;
 }
 
+/// Direction of a [`Scrollbar`], i.e. the overflowing axis of its [`Scrollable`] node.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum ScrollDirection {
+    Vertical,
+    Horizontal,
+}
+
+/// Component of a [`Scrollbar`] recording what its setup did.
+#[derive(Component, Copy, Clone, Debug)]
+pub(crate) struct ScrollbarSetup {
+    /// The [`Scrollable`] node the scrollbar was set up for.
+    scrollable: Entity,
+    /// Direction the thumb is oriented along.
+    direction: ScrollDirection,
+    /// Observer watching the [`Scrollable`] node for `Scroll` triggers.
+    wheel: Entity,
+    /// Observer watching the [`Scrollbar`] for `Click` triggers.
+    click: Entity,
+}
+
+/// Component of a [`Scrollbar`] holding the offsets across the track last given to its thumb, along with that thumb and its direction.
+///
+/// The offsets are only given again when they change, so a thumb supplied by the user can be narrowed and moved across the track in between.
+#[derive(Component, Default, Copy, Clone, Debug)]
+pub(crate) struct ThumbCrossOffsets(Option<(Entity, ScrollDirection, [Val; 2])>);
+
+/// Geometry of the track of a [`Scrollbar`] along its direction, in physical pixels.
+///
+/// The thumb travels along a lane spanning the content box of the track, i.e. within its border and padding, shrunk by the [`ThumbInset`] at both ends. Placing the thumb and mapping trough clicks both go through this type so clicks land where the thumb goes.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct TrackGeometry {
+    /// Offset of the start of the lane from the start of the track.
+    pub(crate) lane_start: f32,
+    /// Length of the lane.
+    pub(crate) lane_length: f32,
+    /// Length of the thumb.
+    pub(crate) thumb_length: f32,
+}
+
+impl TrackGeometry {
+    /// Computes the geometry of `track` along `axis` for a thumb of `thumb_length` physical pixels and an `inset` in logical pixels.
+    pub(crate) fn new(track: &ComputedNode, axis: usize, inset: f32, thumb_length: f32) -> Self {
+        let inset = inset / track.inverse_scale_factor;
+        let content_inset = track.content_inset();
+        let lane_start = content_inset.min_inset[axis] + inset;
+        let lane_length = (track.size[axis]
+            - (content_inset.min_inset[axis] + content_inset.max_inset[axis] + 2.0 * inset))
+            .max(0.0);
+        Self {
+            lane_start,
+            lane_length,
+            thumb_length: thumb_length.min(lane_length),
+        }
+    }
+
+    /// Length along which the thumb can move.
+    pub(crate) fn free_length(&self) -> f32 {
+        self.lane_length - self.thumb_length
+    }
+
+    /// Offset of the start of the thumb from the start of the track when scrolled by `progress`, from 0.0 to 1.0.
+    pub(crate) fn thumb_start(&self, progress: f32) -> f32 {
+        self.lane_start + progress * self.free_length()
+    }
+
+    /// Progress, from 0.0 to 1.0, centering the thumb as close as it goes to `offset` from the start of the track, or `None` if the thumb fills the lane.
+    pub(crate) fn progress_at(&self, offset: f32) -> Option<f32> {
+        let free_length = self.free_length();
+        (free_length > 0.0).then(|| {
+            ((offset - self.lane_start - self.thumb_length / 2.0) / free_length).clamp(0.0, 1.0)
+        })
+    }
+}
+
 /// `on_add` hook of [`Scrollbar`].
 fn spawn_thumb_and_observers(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
     let &Scrollbar { scrollable } = world.get::<Scrollbar>(entity).unwrap();
     world.commands().queue(move |world: &mut World| {
-        let Ok(mut scrollable) = world.get_entity_mut(scrollable) else {
+        if !world.entities().contains(entity) {
             warn!(
-                "Scrollbar setup aborted. Scrollable entity {} does not exist.",
-                scrollable.index()
+                "Scrollbar setup aborted. Scrollbar entity {} does not exist.",
+                entity.index()
             );
             return;
-        };
+        }
 
-        let Some(mut node) = scrollable.get_mut::<Node>() else {
-            warn!(
-                "Scrollbar setup aborted. Scrollable entity {} is missing the Node component.",
-                scrollable.id().index()
-            );
+        // Observe the scrollable node for mouse Scroll triggers and the scrollbar for Click triggers
+        let Some((direction, wheel)) = set_up_scrollable(world, scrollable) else {
             return;
         };
+        let click = world
+            .spawn(Observer::new(jump_content_on_trough_click).with_entity(entity))
+            .id();
+        world.entity_mut(entity).insert(ScrollbarSetup {
+            scrollable,
+            direction,
+            wheel,
+            click,
+        });
 
-        enum ScrollDirection {
-            Vertical,
-            Horizontal,
-        }
+        // Wait for the relationship of a thumb supplied by the user to be applied before spawning one
+        world.commands().queue(move |world: &mut World| {
+            let Ok(scrollbar) = world.get_entity(entity) else {
+                return;
+            };
 
-        // Choose an overflowing axis on the scrollable node if none is set
-        let direction = match (node.overflow.x, node.overflow.y) {
-            (_, OverflowAxis::Scroll) => ScrollDirection::Vertical,
-            (OverflowAxis::Scroll, _) => ScrollDirection::Horizontal,
-            (_, _) => {
-                node.overflow = Overflow::scroll_y();
-                ScrollDirection::Vertical
+            // Keep the thumb supplied by the user
+            if scrollbar.contains::<Thumb>() {
+                return;
             }
-        };
 
-        // Set line height on the scrollable node if none is set and the scrollbar is vertical
-        if matches!(direction, ScrollDirection::Vertical)
-            && !scrollable.contains::<ScrollableLineHeight>()
-        {
-            scrollable.insert(ScrollableLineHeight::default());
+            // Otherwise spawn the thumb
+            let border_radius = match scrollbar.get::<ThumbRadius>() {
+                Some(&ThumbRadius(border_radius)) => border_radius,
+                None => scrollbar.get::<Node>().unwrap().border_radius,
+            };
+            let style = scrollbar.get::<ThumbStyle>().unwrap().clone();
+            // The thumb spans the track across between the offsets set from its ThumbInset
+            let (width, height) = match direction {
+                ScrollDirection::Vertical => (Val::Auto, Val::ZERO),
+                ScrollDirection::Horizontal => (Val::ZERO, Val::Auto),
+            };
+            let node = Node {
+                width,
+                height,
+                border: style.border,
+                border_radius,
+                // Center the grip
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            };
+
+            let thumb_color = scrollbar.get::<ThumbColor>().unwrap().0;
+            let mut thumb = world.spawn((
+                node,
+                ChildOf(entity),
+                ThumbOf { scrollbar: entity },
+                style.border_color,
+            ));
+            match style.image {
+                Some(image) => thumb.insert(ImageNode {
+                    color: thumb_color,
+                    image,
+                    image_mode: style.image_mode,
+                    ..default()
+                }),
+                None => thumb.insert(BackgroundColor(thumb_color)),
+            };
+            if let Some(outline) = style.outline {
+                thumb.insert(outline);
+            }
+            if let Some(grip) = style.grip {
+                thumb.with_child((
+                    Node {
+                        width: Val::Px(grip.size.x),
+                        height: Val::Px(grip.size.y),
+                        flex_shrink: 0.0,
+                        ..default()
+                    },
+                    ImageNode::new(grip.image).with_color(grip.color),
+                ));
+            }
+        });
+    });
+}
+
+/// Chooses the direction of a [`Scrollbar`] from the overflow of its `scrollable` node and spawns an observer watching that node for `Scroll` triggers.
+///
+/// Returns the direction and the observer, or `None` if `scrollable` is not a `Node`.
+fn set_up_scrollable(world: &mut World, scrollable: Entity) -> Option<(ScrollDirection, Entity)> {
+    let Ok(mut scrollable) = world.get_entity_mut(scrollable) else {
+        warn!(
+            "Scrollbar setup aborted. Scrollable entity {} does not exist.",
+            scrollable.index()
+        );
+        return None;
+    };
+
+    let Some(mut node) = scrollable.get_mut::<Node>() else {
+        warn!(
+            "Scrollbar setup aborted. Scrollable entity {} is missing the Node component.",
+            scrollable.id().index()
+        );
+        return None;
+    };
+
+    // Choose an overflowing axis on the scrollable node if none is set
+    let direction = match (node.overflow.x, node.overflow.y) {
+        (_, OverflowAxis::Scroll) => ScrollDirection::Vertical,
+        (OverflowAxis::Scroll, _) => ScrollDirection::Horizontal,
+        (_, _) => {
+            node.overflow = Overflow::scroll_y();
+            ScrollDirection::Vertical
         }
+    };
 
-        // Observe the scrollable node for mouse Scroll triggers
-        scrollable.observe(scroll_content_on_mouse_scroll);
+    // Set line height on the scrollable node if none is set and the scrollbar is vertical
+    if direction == ScrollDirection::Vertical && !scrollable.contains::<ScrollableLineHeight>() {
+        scrollable.insert(ScrollableLineHeight::default());
+    }
 
-        let Ok(mut scrollbar) = world.get_entity_mut(entity) else {
-            warn!(
-                "Scrollbar setup aborted. Scrollbar entity {} does not exist.",
-                entity.index()
-            );
+    let scrollable = scrollable.id();
+    let wheel = world
+        .spawn(Observer::new(scroll_content_on_mouse_scroll).with_entity(scrollable))
+        .id();
+    Some((direction, wheel))
+}
+
+/// Despawns the `wheel` observer of a [`Scrollbar`] and removes the state it kept on its `scrollable` node.
+fn tear_down_scrollable(commands: &mut Commands, scrollable: Entity, wheel: Entity) {
+    // The scrollable node may be despawned along with the scrollbar
+    commands.entity(wheel).try_despawn();
+    commands.entity(scrollable).try_remove::<(
+        ScrollMetrics,
+        ScrollProgress,
+        PendingProgress,
+        LastContentSize,
+        WheelStreak,
+    )>();
+}
+
+/// Swaps the width and height of the `Node` of a thumb so it lies along the other direction, and resets its offsets.
+///
+/// This preserves the thickness of a thumb supplied by the user. Its length and offsets are recomputed by [`ScrollbarSystems`](crate::ScrollbarSystems).
+fn orient_thumb(node: &mut Node) {
+    std::mem::swap(&mut node.width, &mut node.height);
+    node.left = Val::Auto;
+    node.right = Val::Auto;
+    node.top = Val::Auto;
+    node.bottom = Val::Auto;
+}
+
+/// `on_remove` hook of [`Scrollbar`].
+///
+/// Despawns the thumb and the observers spawned by [`spawn_thumb_and_observers`], and removes the state of the [`Scrollable`] node, so a [`Scrollbar`] can be removed or despawned and added again without leaving anything behind.
+fn despawn_thumb_and_observers(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    let setup = world.get::<ScrollbarSetup>(entity).copied();
+    let thumb = world.get::<Thumb>(entity).map(Thumb::thumb);
+    let mut commands = world.commands();
+    // Any of these entities may be despawned along with the scrollbar
+    if let Some(setup) = setup {
+        tear_down_scrollable(&mut commands, setup.scrollable, setup.wheel);
+        commands.entity(setup.click).try_despawn();
+        commands.entity(entity).try_remove::<ScrollbarSetup>();
+    }
+    if let Some(thumb) = thumb {
+        commands.entity(thumb).try_despawn();
+    }
+}
+
+/// Observer moving the setup of a [`Scrollbar`] to the new [`Scrollable`] node it is inserted with.
+///
+/// The wheel observer moves to the new node and the thumb is re-oriented if the direction changed. The track and the thumb are otherwise kept as they are.
+pub(crate) fn retarget_scrollbar(insert: On<Insert, Scrollbar>, mut commands: Commands) {
+    let entity = insert.entity;
+    // Run after the setup queued by the on_add hook of a new scrollbar
+    commands.queue(move |world: &mut World| {
+        let Ok(scrollbar) = world.get_entity(entity) else {
             return;
         };
+        let (Some(&Scrollbar { scrollable }), Some(&setup)) = (
+            scrollbar.get::<Scrollbar>(),
+            scrollbar.get::<ScrollbarSetup>(),
+        ) else {
+            return;
+        };
+        if scrollable == setup.scrollable {
+            return;
+        }
+        let thumb = scrollbar.get::<Thumb>().map(Thumb::thumb);
 
-        // Observe the scrollbar for Click triggers
-        scrollbar.observe(jump_content_on_trough_click);
+        tear_down_scrollable(&mut world.commands(), setup.scrollable, setup.wheel);
+        let Some((direction, wheel)) = set_up_scrollable(world, scrollable) else {
+            return;
+        };
+        world.entity_mut(entity).insert(ScrollbarSetup {
+            scrollable,
+            direction,
+            wheel,
+            ..setup
+        });
+        if direction != setup.direction
+            && let Some(mut node) = thumb.and_then(|thumb| world.get_mut::<Node>(thumb))
+        {
+            orient_thumb(&mut node);
+        }
+    });
+}
 
-        // Spawn the thumb and observe it for Drag triggers
-        let border_radius = scrollbar.get::<Node>().unwrap().border_radius;
-        let node = match direction {
-            ScrollDirection::Vertical => Node {
-                width: Val::Percent(100.0),
-                height: Val::ZERO,
-                border_radius,
-                ..default()
-            },
-            ScrollDirection::Horizontal => Node {
-                width: Val::ZERO,
-                height: Val::Percent(100.0),
-                border_radius,
-                ..default()
-            },
+/// Re-orients the thumb of the [`Scrollbar`] of [`Scrollable`] nodes whose overflowing axis changed.
+///
+/// This system runs in `PostUpdate` before `UiSystems::Layout` so the thumb is laid out along its new direction in the same frame.
+pub(crate) fn reorient_thumbs(
+    q_scrollable: Query<(Entity, &Scrollable, &Node, Has<ScrollableLineHeight>), Changed<Node>>,
+    mut q_scrollbar: Query<(&mut ScrollbarSetup, Option<&Thumb>)>,
+    mut q_node: Query<&mut Node, Without<Scrollable>>,
+    mut commands: Commands,
+) {
+    for (entity, scrollable, node, has_line_height) in &q_scrollable {
+        let Ok((mut setup, thumb)) = q_scrollbar.get_mut(scrollable.scrollbar()) else {
+            continue;
+        };
+        let direction = match crate::scroll_axis(node) {
+            Some(1) => ScrollDirection::Vertical,
+            Some(_) => ScrollDirection::Horizontal,
+            None => {
+                warn!(
+                    "Scrollable entity {} has no overflow axis set to OverflowAxis::Scroll. Its scrollbar cannot scroll it.",
+                    entity.index()
+                );
+                continue;
+            }
         };
+        if direction == setup.direction {
+            continue;
+        }
 
-        let thumb_color = scrollbar.get::<ThumbColor>().unwrap().0;
-        world
-            .spawn((node, ChildOf(entity), BackgroundColor(thumb_color)))
-            .observe(scroll_content_on_thumb_drag);
-    });
+        setup.direction = direction;
+        if let Some(mut thumb_node) = thumb.and_then(|thumb| q_node.get_mut(thumb.thumb()).ok()) {
+            orient_thumb(&mut thumb_node);
+        }
+        if direction == ScrollDirection::Vertical && !has_line_height {
+            commands
+                .entity(entity)
+                .insert(ScrollableLineHeight::default());
+        }
+    }
+}
+
+/// Propagates the [`ThumbColor`], [`ThumbRadius`] and [`ThumbInset`] of [`Scrollbar`]s to their thumb when they change.
+///
+/// The offsets of the thumb across the track also account for the padding of the track. They are only written when the thumb, its direction, the [`ThumbInset`] or the padding change, and are otherwise left to the user.
+///
+/// This system runs in `PostUpdate` before `UiSystems::Layout`, after the thumbs are re-oriented, so the thumb is laid out with its new style in the same frame.
+pub(crate) fn style_thumbs(
+    mut q_scrollbar: Query<(
+        Ref<ThumbColor>,
+        Option<Ref<ThumbRadius>>,
+        &ThumbInset,
+        &ScrollbarSetup,
+        &Thumb,
+        &ComputedNode,
+        &mut ThumbCrossOffsets,
+    )>,
+    mut q_thumb: Query<
+        (
+            &mut Node,
+            Option<&mut BackgroundColor>,
+            Option<&mut ImageNode>,
+        ),
+        Without<Scrollbar>,
+    >,
+) {
+    for (color, radius, inset, setup, thumb, track_cnode, mut cross_offsets) in &mut q_scrollbar {
+        let Ok((mut node, background_color, image)) = q_thumb.get_mut(thumb.thumb()) else {
+            continue;
+        };
+
+        // The color of a new thumb is set at its spawning, or left to the user
+        if color.is_changed() && !color.is_added() {
+            if let Some(mut image) = image {
+                image.color = color.0;
+            } else if let Some(mut background_color) = background_color {
+                background_color.0 = color.0;
+            }
+        }
+
+        if let Some(radius) = radius.filter(|radius| radius.is_changed()) {
+            node.border_radius = radius.0;
+        }
+
+        // Keep the thumb within the padding of the track, which is read from the last layout. The layout updates
+        // the padding without triggering change detection, so the offsets are compared to the last ones given
+        let cross_axis = match setup.direction {
+            ScrollDirection::Vertical => 0,
+            ScrollDirection::Horizontal => 1,
+        };
+        let [start, end] =
+            [track_cnode.padding.min_inset, track_cnode.padding.max_inset].map(|padding| {
+                Val::Px(track_cnode.inverse_scale_factor * padding[cross_axis] + inset.0)
+            });
+        let offsets = Some((thumb.thumb(), setup.direction, [start, end]));
+        if cross_offsets.0 == offsets {
+            continue;
+        }
+        cross_offsets.0 = offsets;
+        match setup.direction {
+            ScrollDirection::Vertical => (node.left, node.right) = (start, end),
+            ScrollDirection::Horizontal => (node.top, node.bottom) = (start, end),
+        }
+    }
 }
 
 /// Observer watching a [`Scrollable`] node for `Scroll` triggers.
@@ -141,40 +566,112 @@ fn scroll_content_on_mouse_scroll(
         &mut ScrollPosition,
         &Node,
         &ScrollSpeed,
+        &mut WheelStreak,
+        Option<&ScrollSpeedPerUnit>,
+        Option<&WheelAcceleration>,
         Option<&ScrollableLineHeight>,
+        Option<&WheelDirection>,
+        Option<&mut PageState>,
     )>,
+    wheel_direction: Res<WheelDirection>,
+    time: Res<Time<Real>>,
 ) -> Result {
     let scrollable = scroll.entity;
-    let (mut scroll_position, node, scroll_speed, line_height) =
-        q_scrollable.get_mut(scrollable)?;
-    let mouse_scroll = match (scroll.unit, line_height) {
-        (MouseScrollUnit::Line, Some(line_height)) => scroll.y * line_height.px(),
-        _ => scroll.y,
+    let (
+        mut scroll_position,
+        node,
+        scroll_speed,
+        mut wheel_streak,
+        speed_per_unit,
+        acceleration,
+        line_height,
+        direction,
+        page_state,
+    ) = q_scrollable.get_mut(scrollable)?;
+    let direction = direction.unwrap_or(&wheel_direction);
+    // Nodes scrolling along both axes follow both axes of the wheel
+    let mouse_scroll = match (node.overflow.x, node.overflow.y) {
+        (OverflowAxis::Scroll, OverflowAxis::Scroll) => Vec2::new(scroll.x, scroll.y),
+        (_, OverflowAxis::Scroll) => Vec2::new(0.0, scroll.y),
+        (OverflowAxis::Scroll, _) if direction.vertical_to_horizontal => Vec2::new(scroll.y, 0.0),
+        (OverflowAxis::Scroll, _) => Vec2::new(scroll.x, 0.0),
+        _ => return Ok(()),
     };
-    let scroll = scroll_speed.0 * mouse_scroll;
-    if node.overflow.y == OverflowAxis::Scroll {
-        scroll_position.y -= scroll;
-    } else if node.overflow.x == OverflowAxis::Scroll {
-        scroll_position.x -= scroll;
+    let mouse_scroll = match (scroll.unit, line_height) {
+        (MouseScrollUnit::Line, Some(line_height)) => mouse_scroll * line_height.px(),
+        _ => mouse_scroll,
     };
+    let mut speed = speed_per_unit.map_or(scroll_speed.0, |speed_per_unit| {
+        speed_per_unit.of(scroll.unit, scroll_speed)
+    });
+    if let (MouseScrollUnit::Line, Some(acceleration)) = (scroll.unit, acceleration) {
+        speed *= wheel_streak.accelerate(time.elapsed_secs(), acceleration);
+    }
+    if direction.inverted {
+        speed = -speed;
+    }
+    let delta = speed * mouse_scroll;
+    // Paged content turns a page per wheel notch, or as pixel scrolls add up, unless already turning one
+    if let Some(mut page_state) = page_state {
+        let Some(axis) = crate::scroll_axis(node) else {
+            return Ok(());
+        };
+        let delta = delta[axis];
+        if page_state.is_turning() || delta == 0.0 {
+            return Ok(());
+        }
+        match scroll.unit {
+            MouseScrollUnit::Line => page_state.turns -= delta.signum() as i32,
+            MouseScrollUnit::Pixel => {
+                // Scrolling back starts adding up again
+                if page_state.wheel * delta > 0.0 {
+                    page_state.wheel = 0.0;
+                }
+                page_state.wheel -= delta;
+            }
+        }
+        return Ok(());
+    }
+    scroll_position.0 -= delta;
     Ok(())
 }
 
 /// Observer watching the thumb of the [`Scrollbar`] for `Drag` triggers.
-fn scroll_content_on_thumb_drag(
+pub(crate) fn scroll_content_on_thumb_drag(
     drag: On<Pointer<Drag>>,
-    q_child_of: Query<&ChildOf>,
+    q_thumb_of: Query<&ThumbOf>,
     q_scrollbar: Query<(&Scrollbar, &DragSpeed)>,
-    mut q_scrollable: Query<(&mut ScrollPosition, &Node)>,
+    mut q_scrollable: Query<(
+        &mut ScrollPosition,
+        &Node,
+        &ComputedNode,
+        Option<&VirtualContentSize>,
+        Option<&mut PageState>,
+    )>,
 ) -> Result {
     let thumb = drag.entity;
-    let scrollbar = q_child_of.get(thumb)?.parent();
+    let &ThumbOf { scrollbar } = q_thumb_of.get(thumb)?;
     let (&Scrollbar { scrollable }, drag_speed) = q_scrollbar.get(scrollbar)?;
-    let (mut scroll_position, node) = q_scrollable.get_mut(scrollable)?;
+    let (mut scroll_position, node, cnode, virtual_content_size, page_state) =
+        q_scrollable.get_mut(scrollable)?;
+    // Scale drags over virtual content by the ratio of its size to the laid out content size
+    let scale = virtual_content_size.map_or(Vec2::ONE, |size| {
+        content_size(cnode, Some(size)) / cnode.content_size.max(cnode.size).max(Vec2::ONE)
+    });
+    let scroll = drag_speed.0 * scale * drag.delta;
+    // Paged content turns pages as the drag adds up
+    if let Some(mut page_state) = page_state {
+        page_state.drag += if node.overflow.y == OverflowAxis::Scroll {
+            scroll.y
+        } else {
+            scroll.x
+        };
+        return Ok(());
+    }
     if node.overflow.y == OverflowAxis::Scroll {
-        scroll_position.y += drag_speed.0 * drag.delta.y;
+        scroll_position.y += scroll.y;
     } else if node.overflow.x == OverflowAxis::Scroll {
-        scroll_position.x += drag_speed.0 * drag.delta.x;
+        scroll_position.x += scroll.x;
     };
     Ok(())
 }
@@ -184,9 +681,14 @@ fn scroll_content_on_thumb_drag(
 /// This observer handles clicking the trough (i.e. the region of the track not covered by the thumb). When the trough is clicked, the thumb jumps to that position. Clicks that did not originate from the scrollbar, i.e. clicks on the thumb, are discarded. This system only adjusts the ScrollPosition of the content. update_scroll_position_and_thumb() will see the change and do the rest of the work.
 fn jump_content_on_trough_click(
     click: On<Pointer<Click>>,
-    q_scrollbar: Query<(&Scrollbar, &ComputedNode, &Children)>,
+    q_scrollbar: Query<(&Scrollbar, &ComputedNode, &ThumbInset, &Thumb)>,
     q_node: Query<(&Node, &ComputedNode)>,
-    mut q_scroll_position: Query<&mut ScrollPosition>,
+    mut q_scrollable: Query<(
+        &mut ScrollPosition,
+        Option<&VirtualContentSize>,
+        Option<&WrapAround>,
+        Option<&mut PageState>,
+    )>,
 ) -> Result {
     let scrollbar = click.entity;
     if scrollbar != click.original_event_target() {
@@ -199,39 +701,42 @@ fn jump_content_on_trough_click(
         return Ok(());
     };
 
-    let (&Scrollbar { scrollable }, track_cnode, children) = q_scrollbar.get(scrollbar)?;
-    let thumb = children[0];
-    let (_, thumb_cnode) = q_node.get(thumb)?;
+    let (&Scrollbar { scrollable }, track_cnode, inset, thumb) = q_scrollbar.get(scrollbar)?;
+    let (_, thumb_cnode) = q_node.get(thumb.thumb())?;
     let (scrollable_node, scrollable_cnode) = q_node.get(scrollable)?;
-    let mut scroll_position = q_scroll_position.get_mut(scrollable)?;
-
-    if scrollable_node.overflow.y == OverflowAxis::Scroll {
-        // Compute the offset of the click from the track top in logical pixels
-        // Remember hit coordinates lie between -0.5 and 0.5
-        let offset_y = ((0.5 + click_position.y) * track_cnode.size.y).clamp(
-            thumb_cnode.size.y / 2.0,
-            track_cnode.size.y - thumb_cnode.size.y / 2.0,
-        );
-        let ratio =
-            (offset_y - thumb_cnode.size.y / 2.0) / (track_cnode.size.y - thumb_cnode.size.y);
-        scroll_position.y = track_cnode.inverse_scale_factor
-            * ratio
-            * (scrollable_cnode.content_size.y - scrollable_cnode.size.y);
-        debug!("click_position.y: {}", click_position.y);
-        debug!("offset_y: {offset_y}");
-        debug!("ratio: {}\n", click_position.y);
-    } else if scrollable_node.overflow.x == OverflowAxis::Scroll {
-        // Compute the offset of the click from the track left in logical pixels
-        // Remember hit coordinates lie between -0.5 and 0.5
-        let offset_x = ((0.5 + click_position.x) * track_cnode.size.x).clamp(
-            thumb_cnode.size.x / 2.0,
-            track_cnode.size.x - thumb_cnode.size.x / 2.0,
-        );
-        let ratio =
-            (offset_x - thumb_cnode.size.x / 2.0) / (track_cnode.size.x - thumb_cnode.size.x);
-        scroll_position.x = track_cnode.inverse_scale_factor
-            * ratio
-            * (scrollable_cnode.content_size.x - scrollable_cnode.size.x);
+    let (mut scroll_position, virtual_content_size, wrap, page_state) =
+        q_scrollable.get_mut(scrollable)?;
+    let content_size = content_size(scrollable_cnode, virtual_content_size);
+
+    let Some(axis) = crate::scroll_axis(scrollable_node) else {
+        return Ok(());
+    };
+
+    // Compute the offset of the click from the track start in physical pixels
+    // Remember hit coordinates lie between -0.5 and 0.5
+    let offset = (0.5 + click_position[axis]) * track_cnode.size[axis];
+    let geometry = TrackGeometry::new(track_cnode, axis, inset.0, thumb_cnode.size[axis]);
+    let Some(progress) = geometry.progress_at(offset) else {
+        // The thumb fills the track
+        return Ok(());
+    };
+    let max_offset = max_offset(scrollable_cnode, virtual_content_size)[axis];
+    let position = match wrap {
+        // The track stands for a whole turn of the content, which wraps around once out of range
+        Some(wrap) if max_offset > 0.0 => {
+            scrollable_cnode.inverse_scale_factor * progress * content_size[axis] - wrap.offset()
+        }
+        _ => progress * max_offset,
     };
+    // Paged content animates to the closest page instead
+    match page_state {
+        Some(mut page_state) => {
+            let pages = Pages::new(scrollable_cnode, virtual_content_size, axis);
+            page_state.jump = Some(pages.page_at(position));
+        }
+        None => scroll_position[axis] = position,
+    }
+    debug!("click offset: {offset}");
+    debug!("progress: {progress}\n");
     Ok(())
 }
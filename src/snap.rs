@@ -0,0 +1,217 @@
+use bevy::{picking::pointer::PointerPress, prelude::*};
+
+use crate::{Scrollbar, VirtualContentSize, scrollable::max_offset};
+
+/// Component of a [`Scrollable`](crate::Scrollable) node snapping its content to one of its children once scrolling settles.
+///
+/// Scrolling is considered settled when the [`ScrollPosition`] of the node has not changed for [`ScrollSnap::delay`] seconds and no pointer button pressed on the node or its [`Scrollbar`] is held. The content then animates to the snap point of the child closest to the current position. Snap points are computed from the `ComputedNode`s of the children of the node.
+#[derive(Component, Copy, Clone, Reflect, Debug)]
+#[require(SnapState)]
+pub struct ScrollSnap {
+    /// Alignment of the snapped child in the viewport.
+    pub align: SnapAlign,
+    /// Whether the content always snaps or only when close to a snap point.
+    pub strictness: SnapStrictness,
+    /// Time in seconds during which the scrolling must be idle before snapping.
+    pub delay: f32,
+    /// Rate of the snapping animation. Higher is faster.
+    pub speed: f32,
+}
+
+impl Default for ScrollSnap {
+    fn default() -> Self {
+        Self {
+            align: SnapAlign::Start,
+            strictness: SnapStrictness::Mandatory,
+            delay: 0.15,
+            speed: 15.0,
+        }
+    }
+}
+
+impl ScrollSnap {
+    /// Creates a mandatory [`ScrollSnap`] with the given alignment.
+    pub fn new(align: SnapAlign) -> Self {
+        Self { align, ..default() }
+    }
+
+    /// Sets the strictness of the snapping.
+    pub fn with_strictness(mut self, strictness: SnapStrictness) -> Self {
+        self.strictness = strictness;
+        self
+    }
+}
+
+/// Alignment of a snapped child in the viewport of a [`ScrollSnap`] node.
+#[derive(Default, Copy, Clone, PartialEq, Eq, Reflect, Debug)]
+pub enum SnapAlign {
+    /// Aligns the start of the child with the start of the viewport.
+    #[default]
+    Start,
+    /// Aligns the center of the child with the center of the viewport.
+    Center,
+    /// Aligns the end of the child with the end of the viewport.
+    End,
+}
+
+/// Strictness of a [`ScrollSnap`].
+#[derive(Default, Copy, Clone, PartialEq, Reflect, Debug)]
+pub enum SnapStrictness {
+    /// The content always snaps to the closest snap point.
+    #[default]
+    Mandatory,
+    /// The content only snaps if the closest snap point is within this distance in logical pixels.
+    Proximity(f32),
+}
+
+/// Component of a [`ScrollSnap`] node tracking the state of its snapping.
+#[derive(Component, Default, Copy, Clone, Debug)]
+pub(crate) struct SnapState {
+    /// Scroll position observed on the previous frame, along the scrolling axis.
+    last_position: f32,
+    /// Time in seconds since the scroll position last changed.
+    idle: f32,
+    /// Snap point the content is animating to.
+    target: Option<f32>,
+    /// Whether the content is done snapping.
+    settled: bool,
+    /// Whether a pointer button pressed on the node or its [`Scrollbar`] is held.
+    held: bool,
+}
+
+/// Observer holding the snapping of [`ScrollSnap`] nodes pressed, or whose [`Scrollbar`] is pressed, until no pointer button is pressed anymore.
+///
+/// Presses bubble up from the children of the node and from the thumb to the track, so they all reach this observer.
+pub(crate) fn hold_snap_on_press(
+    press: On<Pointer<Press>>,
+    q_scrollbar: Query<&Scrollbar>,
+    mut q_state: Query<&mut SnapState>,
+) {
+    let scrollable = q_scrollbar
+        .get(press.entity)
+        .map_or(press.entity, |scrollbar| scrollbar.scrollable);
+    if let Ok(mut state) = q_state.get_mut(scrollable) {
+        state.held = true;
+    }
+}
+
+/// Animates the [`ScrollPosition`] of settled [`ScrollSnap`] nodes to their closest snap point.
+///
+/// This system runs in `PostUpdate` before `UiSystems::Layout` so the snapped position is laid out in the same frame. Snap points are computed from the layout of the previous frame.
+pub(crate) fn snap_scroll_position(
+    mut q_scrollable: Query<(
+        &ScrollSnap,
+        &mut SnapState,
+        &mut ScrollPosition,
+        &Node,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &Children,
+        Option<&VirtualContentSize>,
+    )>,
+    q_child: Query<(&ComputedNode, &UiGlobalTransform)>,
+    q_pointer_press: Query<&PointerPress>,
+    time: Res<Time>,
+) {
+    let pressed = q_pointer_press.iter().any(PointerPress::is_any_pressed);
+    for (
+        snap,
+        mut state,
+        mut scroll_position,
+        node,
+        cnode,
+        transform,
+        children,
+        virtual_content_size,
+    ) in &mut q_scrollable
+    {
+        let Some(axis) = crate::scroll_axis(node) else {
+            continue;
+        };
+
+        // Presses elsewhere, e.g. on a slider, leave the node free to snap
+        state.held &= pressed;
+        let position = scroll_position[axis];
+        if position != state.last_position || state.held {
+            *state = SnapState {
+                last_position: position,
+                held: state.held,
+                ..default()
+            };
+            continue;
+        }
+        if state.settled {
+            continue;
+        }
+        state.idle += time.delta_secs();
+        if state.idle < snap.delay {
+            continue;
+        }
+
+        let target = match state.target {
+            Some(target) => target,
+            None => {
+                let snap_points = children
+                    .iter()
+                    .filter_map(|child| q_child.get(child).ok())
+                    .map(|(child_cnode, child_transform)| {
+                        snap_point(
+                            snap.align,
+                            axis,
+                            cnode,
+                            transform,
+                            child_cnode,
+                            child_transform,
+                        )
+                    });
+                let closest = snap_points
+                    .min_by(|a, b| (a - position).abs().total_cmp(&(b - position).abs()));
+                let Some(closest) = closest.filter(|&closest| match snap.strictness {
+                    SnapStrictness::Mandatory => true,
+                    SnapStrictness::Proximity(distance) => (closest - position).abs() <= distance,
+                }) else {
+                    state.settled = true;
+                    continue;
+                };
+                let max_offset = max_offset(cnode, virtual_content_size)[axis];
+                let target = closest.clamp(0.0, max_offset);
+                state.target = Some(target);
+                target
+            }
+        };
+
+        let position = if (target - position).abs() <= 0.5 {
+            state.settled = true;
+            target
+        } else {
+            position + (target - position) * (1.0 - (-snap.speed * time.delta_secs()).exp())
+        };
+        scroll_position[axis] = position;
+        state.last_position = position;
+    }
+}
+
+/// Returns the scroll position in logical pixels snapping `child` along `axis`.
+fn snap_point(
+    align: SnapAlign,
+    axis: usize,
+    cnode: &ComputedNode,
+    transform: &UiGlobalTransform,
+    child_cnode: &ComputedNode,
+    child_transform: &UiGlobalTransform,
+) -> f32 {
+    // Offset of the child from the start of the content box when the content is not scrolled
+    let content_start = transform.translation[axis] - cnode.size[axis] / 2.0
+        + cnode.content_inset().min_inset[axis];
+    let child_start =
+        child_transform.translation[axis] - child_cnode.size[axis] / 2.0 - content_start
+            + cnode.scroll_position[axis];
+    let viewport_length = cnode.size[axis]
+        - (cnode.content_inset().min_inset[axis] + cnode.content_inset().max_inset[axis]);
+    let scaled_snap_point = match align {
+        SnapAlign::Start => child_start,
+        SnapAlign::Center => child_start + (child_cnode.size[axis] - viewport_length) / 2.0,
+        SnapAlign::End => child_start + child_cnode.size[axis] - viewport_length,
+    };
+    cnode.inverse_scale_factor * scaled_snap_point
+}
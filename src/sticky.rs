@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use crate::{Scrollable, Scrollbar};
+
+/// Component of a child of a [`Scrollable`] node pinning it at the start of the viewport while its section is scrolled through.
+///
+/// The section of a header spans from it to the next [`StickyHeader`] among the children of the node, or to the end of the content for the last one. Once its section reaches the start of the viewport, the header stays there until the next header pushes it out. Headers pin at the top of vertical nodes and at the left of horizontal ones.
+///
+/// The header is moved through its `UiTransform`, so it is still laid out in the flow of the content and picked where it is drawn. Its `ZIndex` is raised so it is drawn over the content of its section, which it should hide with a background.
+#[derive(Component, Default, Copy, Clone, Reflect, Debug)]
+#[require(Node, ZIndex(1), StickyOffset)]
+pub struct StickyHeader;
+
+/// Component of a [`StickyHeader`] holding how far it is moved from its place in the layout, in physical pixels.
+#[derive(Component, Default, Copy, Clone, Debug)]
+pub(crate) struct StickyOffset(f32);
+
+/// Pins the [`StickyHeader`]s of [`Scrollable`] nodes at the start of their viewport.
+///
+/// This system runs in `PostUpdate` between `UiSystems::Layout` and `UiSystems::PostLayout`. The `UiTransform` of the headers is updated for the next layout and, so the headers are not drawn a frame late, their `UiGlobalTransform` and those of their descendants are updated to their final values right away.
+pub(crate) fn pin_sticky_headers(
+    q_scrollable: Query<(&Node, &ComputedNode, &UiGlobalTransform, &Children), With<Scrollable>>,
+    mut q_header: Query<(&ComputedNode, &mut UiTransform, &mut StickyOffset), With<StickyHeader>>,
+    mut q_transform: Query<&mut UiGlobalTransform, (Without<Scrollbar>, Without<Scrollable>)>,
+    q_children: Query<&Children>,
+) -> Result {
+    for (node, cnode, transform, children) in &q_scrollable {
+        let Some(axis) = crate::scroll_axis(node) else {
+            continue;
+        };
+        let viewport_start = transform.translation[axis] - cnode.size[axis] / 2.0
+            + cnode.content_inset().min_inset[axis];
+
+        // Place of each header in the layout, without its offset
+        let mut headers = Vec::new();
+        for &child in children {
+            let Ok((header_cnode, _, offset)) = q_header.get(child) else {
+                continue;
+            };
+            let center = q_transform.get(child)?.translation[axis] - offset.0;
+            let length = header_cnode.size[axis];
+            headers.push((child, center - length / 2.0, length));
+        }
+
+        for (index, &(header, start, length)) in headers.iter().enumerate() {
+            // The header is pinned at the start of the viewport, but stays above the next header
+            let mut shift = (viewport_start - start).max(0.0);
+            if let Some(&(_, next_start, _)) = headers.get(index + 1) {
+                shift = shift.min((next_start - start - length).max(0.0));
+            }
+
+            let (header_cnode, mut ui_transform, mut offset) = q_header.get_mut(header)?;
+            let translation = Val::Px(header_cnode.inverse_scale_factor * shift);
+            match axis {
+                1 if ui_transform.translation.y != translation => {
+                    ui_transform.translation.y = translation;
+                }
+                0 if ui_transform.translation.x != translation => {
+                    ui_transform.translation.x = translation;
+                }
+                _ => {}
+            }
+            if offset.0 != shift {
+                offset.0 = shift;
+                crate::place_node(
+                    header,
+                    axis,
+                    start + length / 2.0 + shift,
+                    &mut q_transform,
+                    &q_children,
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use bevy::prelude::*;
+
+use crate::{ScrollLink, Scrollable, Scrollbar};
+
+/// Component of a `Node` laying out a table with a frozen header row and frozen columns.
+///
+/// The children of the table are its regions, marked with [`ScrollTableHeader`], [`ScrollTableColumns`], [`ScrollTableBody`] and optionally [`ScrollTableCorner`]. The table is laid out as a grid by [`ScrollbarPreLayoutSystems`](crate::ScrollbarPreLayoutSystems):
+/// * the header sits above the body and only follows its horizontal scroll;
+/// * the frozen columns sit left of the body and only follow its vertical scroll;
+/// * the body scrolls both ways, with a vertical [`Scrollbar`] on its right;
+/// * the header gets a horizontal [`Scrollbar`] below the body, which scrolls the body along;
+/// * the corner sits above the frozen columns and never scrolls.
+///
+/// The regions are kept aligned through [`ScrollLink`]s whose group is the table entity, so their contents should have the same width (header and body) or height (frozen columns and body). Mutating this component lays the table out again.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_scrollbar::{ScrollTable, ScrollTableBody, ScrollTableColumns, ScrollTableHeader};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         Node {
+///             width: Val::Px(400.0),
+///             height: Val::Px(300.0),
+///             ..default()
+///         },
+///         ScrollTable::default().with_header_height(30.0),
+///         children![
+///             (ScrollTableHeader, Children::spawn_one(Text::new("Header"))),
+///             (ScrollTableColumns, Children::spawn_one(Text::new("Column"))),
+///             (ScrollTableBody, Children::spawn_one(Text::new("Cells"))),
+///         ],
+///     ));
+/// }
+/// ```
+#[derive(Component, Copy, Clone, Reflect, Debug)]
+#[require(Node)]
+pub struct ScrollTable {
+    /// Height of the header row in logical pixels.
+    pub header_height: f32,
+    /// Width of the frozen columns in logical pixels.
+    pub columns_width: f32,
+    /// Width of the [`Scrollbar`]s across their track in logical pixels.
+    pub scrollbar_width: f32,
+}
+
+impl Default for ScrollTable {
+    fn default() -> Self {
+        Self {
+            header_height: 20.0,
+            columns_width: 60.0,
+            scrollbar_width: 8.0,
+        }
+    }
+}
+
+impl ScrollTable {
+    /// Sets the height of the header row.
+    pub fn with_header_height(mut self, height: f32) -> Self {
+        self.header_height = height;
+        self
+    }
+
+    /// Sets the width of the frozen columns.
+    pub fn with_columns_width(mut self, width: f32) -> Self {
+        self.columns_width = width;
+        self
+    }
+
+    /// Sets the width of the [`Scrollbar`]s.
+    pub fn with_scrollbar_width(mut self, width: f32) -> Self {
+        self.scrollbar_width = width;
+        self
+    }
+}
+
+/// Component of the header region of a [`ScrollTable`], which only scrolls horizontally.
+#[derive(Component, Default, Copy, Clone, Reflect, Debug)]
+#[require(Node)]
+pub struct ScrollTableHeader;
+
+/// Component of the frozen columns region of a [`ScrollTable`], which only scrolls vertically.
+#[derive(Component, Default, Copy, Clone, Reflect, Debug)]
+#[require(Node)]
+pub struct ScrollTableColumns;
+
+/// Component of the body region of a [`ScrollTable`], which scrolls both ways.
+#[derive(Component, Default, Copy, Clone, Reflect, Debug)]
+#[require(Node)]
+pub struct ScrollTableBody;
+
+/// Component of the corner region of a [`ScrollTable`], above the frozen columns, which never scrolls.
+#[derive(Component, Default, Copy, Clone, Reflect, Debug)]
+#[require(Node)]
+pub struct ScrollTableCorner;
+
+/// Lays out the regions of [`ScrollTable`]s and spawns their [`Scrollbar`]s.
+///
+/// This system runs in `PostUpdate` before `UiSystems::Layout` when a table is added or changed or its children change, so regions may be added after the table.
+pub(crate) fn lay_out_tables(
+    mut q_table: Query<
+        (Entity, &ScrollTable, &mut Node, &Children),
+        Or<(Changed<ScrollTable>, Changed<Children>)>,
+    >,
+    mut q_region: Query<
+        (
+            &mut Node,
+            Has<ScrollTableHeader>,
+            Has<ScrollTableColumns>,
+            Has<ScrollTableBody>,
+            Has<ScrollLink>,
+            Has<Scrollable>,
+        ),
+        (
+            Without<ScrollTable>,
+            Or<(
+                With<ScrollTableHeader>,
+                With<ScrollTableColumns>,
+                With<ScrollTableBody>,
+                With<ScrollTableCorner>,
+            )>,
+        ),
+    >,
+    mut commands: Commands,
+) {
+    for (table, &table_config, mut table_node, children) in &mut q_table {
+        table_node.display = Display::Grid;
+        table_node.grid_template_columns = vec![
+            GridTrack::px(table_config.columns_width),
+            GridTrack::flex(1.0),
+            GridTrack::px(table_config.scrollbar_width),
+        ];
+        table_node.grid_template_rows = vec![
+            GridTrack::px(table_config.header_height),
+            GridTrack::flex(1.0),
+            GridTrack::px(table_config.scrollbar_width),
+        ];
+
+        for &child in children {
+            let Ok((mut node, header, columns, body, linked, scrollable)) = q_region.get_mut(child)
+            else {
+                continue;
+            };
+            let (row, column, overflow, link) = if header {
+                (1, 2, Overflow::scroll_x(), ScrollLink::horizontal(table))
+            } else if columns {
+                (2, 1, Overflow::scroll_y(), ScrollLink::vertical(table))
+            } else if body {
+                let link = ScrollLink::horizontal(table).with_vertical(table);
+                (2, 2, Overflow::scroll(), link)
+            } else {
+                // The corner is only placed
+                node.grid_row = GridPlacement::start(1);
+                node.grid_column = GridPlacement::start(1);
+                continue;
+            };
+            node.grid_row = GridPlacement::start(row);
+            node.grid_column = GridPlacement::start(column);
+            node.overflow = overflow;
+            if !linked {
+                commands.entity(child).insert(link);
+            }
+
+            // The body scrolls vertically with its own scrollbar and horizontally with the one of the header
+            if !scrollable && (header || body) {
+                let (row, column) = if header { (3, 2) } else { (2, 3) };
+                commands.spawn((
+                    Scrollbar { scrollable: child },
+                    Node {
+                        grid_row: GridPlacement::start(row),
+                        grid_column: GridPlacement::start(column),
+                        ..default()
+                    },
+                    ChildOf(table),
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Helpers simulating user input on scrollbars without a window.
+//!
+//! These helpers trigger the same `Pointer` events as the picking backend, so they run the same observers as real input. The resulting [`ScrollPosition`] is clamped, and the thumb moved, by [`ScrollbarSystems`](crate::ScrollbarSystems) on the next `App::update`.
+//!
+//! This module is only available with the `testing` feature.
+
+use bevy::{
+    camera::NormalizedRenderTarget,
+    input::mouse::MouseScrollUnit,
+    picking::{
+        backend::HitData,
+        pointer::{Location, PointerButton, PointerId},
+    },
+    prelude::*,
+};
+
+use crate::Thumb;
+
+/// Returns the thumb of `scrollbar`, if it has been spawned.
+pub fn thumb_entity(world: &World, scrollbar: Entity) -> Option<Entity> {
+    world.get::<Thumb>(scrollbar).map(Thumb::thumb)
+}
+
+/// Scrolls the mouse wheel over `scrollable` by `delta` pixels.
+///
+/// As with a real wheel, a negative `delta.y` scrolls the content forward.
+pub fn simulate_wheel(world: &mut World, scrollable: Entity, delta: Vec2) {
+    world.trigger(Pointer::new(
+        PointerId::Mouse,
+        location(),
+        Scroll {
+            unit: MouseScrollUnit::Pixel,
+            x: delta.x,
+            y: delta.y,
+            hit: hit(None),
+        },
+        scrollable,
+    ));
+}
+
+/// Drags the thumb of `scrollbar` by `delta` pixels.
+pub fn simulate_thumb_drag(world: &mut World, scrollbar: Entity, delta: Vec2) -> Result {
+    let thumb = thumb_entity(world, scrollbar).ok_or("Scrollbar has no thumb to drag")?;
+    world.trigger(Pointer::new(
+        PointerId::Mouse,
+        location(),
+        Drag {
+            button: PointerButton::Primary,
+            distance: delta,
+            delta,
+        },
+        thumb,
+    ));
+    Ok(())
+}
+
+/// Clicks the track of `scrollbar` at `fraction` of its length, from 0.0 at its start to 1.0 at its end.
+///
+/// The click targets the track itself, as clicks on the trough do, even where the thumb covers it.
+pub fn simulate_trough_click(world: &mut World, scrollbar: Entity, fraction: f32) {
+    // Hit positions lie between -0.5 and 0.5 along both axes of the track
+    simulate_click(world, scrollbar, Vec2::splat(fraction - 0.5));
+}
+
+/// Clicks `entity` at `position`, in coordinates between -0.5 and 0.5 along both axes of the node.
+///
+/// Use it to click the thumb, the dots of a [`PageIndicator`](crate::PageIndicator) or any other node.
+pub fn simulate_click(world: &mut World, entity: Entity, position: Vec2) {
+    world.trigger(Pointer::new(
+        PointerId::Mouse,
+        location(),
+        Click {
+            button: PointerButton::Primary,
+            hit: hit(Some(position.extend(0.0))),
+            duration: default(),
+        },
+        entity,
+    ));
+}
+
+/// Returns the location of a simulated pointer.
+fn location() -> Location {
+    Location {
+        target: NormalizedRenderTarget::None {
+            width: 0,
+            height: 0,
+        },
+        position: Vec2::ZERO,
+    }
+}
+
+/// Returns the hit data of a simulated pointer.
+fn hit(position: Option<Vec3>) -> HitData {
+    HitData::new(Entity::PLACEHOLDER, 0.0, position, None)
+}
@@ -0,0 +1,73 @@
+use bevy::{
+    ecs::{lifecycle::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+use log::warn;
+
+use crate::scrollbar::scroll_content_on_thumb_drag;
+
+/// Component of the thumb of a [`Scrollbar`](crate::Scrollbar).
+///
+/// Adding this component to an entity makes it the thumb of the [`Scrollbar`](crate::Scrollbar) entity, which gets the [`Thumb`] component pointing back at it. The thumb is located through this relationship rather than by its position among the children of the track.
+///
+/// A [`Scrollbar`](crate::Scrollbar) spawned without a thumb spawns its own, styled by [`ThumbColor`](crate::ThumbColor) and [`ThumbStyle`](crate::ThumbStyle). To supply your own thumb instead, with arbitrary children such as icons or labels, spawn it along with the [`Scrollbar`](crate::Scrollbar) through `Thumb::spawn_one`, or insert this component on an existing entity before the [`Scrollbar`](crate::Scrollbar) is set up at the next command flush. The thumb is then made a child of the track if it is not already one, and absolutely positioned in it. Its length and position along the track are managed by [`ScrollbarSystems`](crate::ScrollbarSystems), and it spans the track across unless you give it a size across it. Its offsets across the track are only set when it is related, re-oriented, or when the [`ThumbInset`](crate::ThumbInset) or the padding of the track change, so you may move a narrower thumb across the track in between. Its look and its children are left to you, though mutating the [`ThumbColor`](crate::ThumbColor), [`ThumbRadius`](crate::ThumbRadius) or [`ThumbInset`](crate::ThumbInset) of the [`Scrollbar`](crate::Scrollbar) updates it.
+#[derive(Component, Clone, Reflect, Debug)]
+#[relationship(relationship_target = Thumb)]
+#[require(Node)]
+#[component(immutable)]
+#[component(on_add = lay_out_and_observe_thumb)]
+pub struct ThumbOf {
+    /// The [`Scrollbar`](crate::Scrollbar) entity of this thumb.
+    pub scrollbar: Entity,
+}
+
+/// Component of a [`Scrollbar`](crate::Scrollbar) pointing at its thumb.
+///
+/// Adding this component to an entity makes it the `RelationshipTarget` of a [`ThumbOf`] entity. Despawning this entity will also despawn that thumb. See [`ThumbOf`] for more information.
+///
+/// Note: As `Children`, this component is not inserted directly. It is
+/// * either automatically inserted when the [`Scrollbar`](crate::Scrollbar) spawns its thumb or when you insert [`ThumbOf`] on an entity;
+/// * or inserted via `SpawnRelated::spawn_one` to supply a custom thumb.
+#[derive(Component, Clone, Reflect, Debug)]
+#[relationship_target(relationship = ThumbOf, linked_spawn)]
+pub struct Thumb {
+    /// The thumb entity of this scrollbar entity.
+    thumb: Entity,
+}
+
+impl Thumb {
+    /// Gets the thumb entity of this [`Scrollbar`](crate::Scrollbar) entity.
+    pub fn thumb(&self) -> Entity {
+        self.thumb
+    }
+}
+
+/// `on_add` hook of [`ThumbOf`].
+fn lay_out_and_observe_thumb(mut world: DeferredWorld, HookContext { entity, .. }: HookContext) {
+    let &ThumbOf { scrollbar } = world.get::<ThumbOf>(entity).unwrap();
+    world.commands().queue(move |world: &mut World| {
+        let Ok(mut thumb) = world.get_entity_mut(entity) else {
+            warn!(
+                "Thumb setup aborted. Thumb entity {} does not exist.",
+                entity.index()
+            );
+            return;
+        };
+
+        // Take the thumb out of the flow of the track so its other children neither move it nor are moved by it
+        if let Some(mut node) = thumb.get_mut::<Node>() {
+            node.position_type = PositionType::Absolute;
+        }
+
+        // Lay the thumb out in the track
+        if thumb
+            .get::<ChildOf>()
+            .is_none_or(|child_of| child_of.parent() != scrollbar)
+        {
+            thumb.insert(ChildOf(scrollbar));
+        }
+
+        // Observe the thumb for Drag triggers
+        thumb.observe(scroll_content_on_thumb_drag);
+    });
+}
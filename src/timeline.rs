@@ -0,0 +1,111 @@
+use std::{fmt, sync::Arc};
+
+use bevy::prelude::*;
+
+use crate::{Scrollable, VirtualContentSize, WrapAround, scrollable::progress};
+
+/// Component of an entity animated by the scroll progress of a [`Scrollable`] node, e.g. for parallax backgrounds, collapsing headers or fade-ins.
+///
+/// Whenever the [`Scrollable`] node scrolls, its progress along its scrolling axis, from `0.0` at the start of the content to `1.0` at its end, is mapped onto the domain of the curve of the timeline. The curve is sampled there and the sample is handed to the callback of the timeline along with this entity, which applies it.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_scrollbar::ScrollTimeline;
+/// fn fade_in(mut commands: Commands, scrollable: Entity) {
+///     let curve = EasingCurve::new(0.0, 1.0, EaseFunction::QuadraticIn);
+///     commands.spawn((
+///         Node::default(),
+///         ScrollTimeline::new(scrollable, curve, |alpha, entity| {
+///             entity.insert(BackgroundColor(Color::WHITE.with_alpha(alpha)));
+///         }),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone)]
+#[require(TimelineState)]
+pub struct ScrollTimeline {
+    /// The [`Scrollable`] node driving this timeline.
+    pub scrollable: Entity,
+    /// Callback sampling the curve at the given progress and applying the sample to the entity.
+    apply: Arc<dyn Fn(f32, &mut EntityCommands) + Send + Sync>,
+}
+
+impl ScrollTimeline {
+    /// Creates a [`ScrollTimeline`] sampling `curve` with the progress of `scrollable` and applying the samples with `apply`.
+    ///
+    /// A curve with an unbounded domain is sampled at the progress itself.
+    pub fn new<T>(
+        scrollable: Entity,
+        curve: impl Curve<T> + Send + Sync + 'static,
+        apply: impl Fn(T, &mut EntityCommands) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            scrollable,
+            apply: Arc::new(move |progress, entity| {
+                let domain = curve.domain();
+                let t = if domain.is_bounded() {
+                    domain.start() + progress * domain.length()
+                } else {
+                    progress
+                };
+                apply(curve.sample_clamped(t), entity);
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for ScrollTimeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScrollTimeline")
+            .field("scrollable", &self.scrollable)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Component of a [`ScrollTimeline`] entity tracking the sampling of its curve.
+#[derive(Component, Default, Copy, Clone, Debug)]
+pub(crate) struct TimelineState {
+    /// Progress the curve was last sampled at.
+    last_progress: Option<f32>,
+}
+
+/// Samples the curves of [`ScrollTimeline`]s whose [`Scrollable`] node scrolled.
+///
+/// This system runs in `PostUpdate` before `UiSystems::Layout`, after the other systems moving the content, so the samples are laid out in the same frame as the scroll. The progress is computed from the content size of the previous layout.
+pub(crate) fn sample_scroll_timelines(
+    mut q_timeline: Query<(Entity, &ScrollTimeline, &mut TimelineState)>,
+    q_scrollable: Query<
+        (
+            &ScrollPosition,
+            &Node,
+            &ComputedNode,
+            Option<&VirtualContentSize>,
+            Option<&WrapAround>,
+        ),
+        With<Scrollable>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, timeline, mut state) in &mut q_timeline {
+        let Ok((scroll_position, node, cnode, virtual_content_size, wrap)) =
+            q_scrollable.get(timeline.scrollable)
+        else {
+            continue;
+        };
+        let Some(axis) = crate::scroll_axis(node) else {
+            continue;
+        };
+        let progress = progress(
+            scroll_position[axis],
+            axis,
+            cnode,
+            virtual_content_size,
+            wrap,
+        );
+
+        if state.last_progress != Some(progress) {
+            state.last_progress = Some(progress);
+            (timeline.apply)(progress, &mut commands.entity(entity));
+        }
+    }
+}
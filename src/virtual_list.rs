@@ -0,0 +1,236 @@
+use std::{fmt, sync::Arc};
+
+use bevy::{platform::collections::HashMap, prelude::*};
+
+/// Component of a vertical [`Scrollable`](crate::Scrollable) node displaying a list of rows of which only the visible ones are spawned.
+///
+/// The list spawns a single child node as tall as all its rows together, so the thumb of the [`Scrollbar`](crate::Scrollbar) reflects the full length of the list. Only the rows intersecting the viewport, plus [`VirtualList::overscan`] rows on each side, are spawned as absolutely positioned children of that node. Each row is spawned as a `Node` with a [`VirtualRow`] component and handed to the callback of the list, which inserts the content of the row.
+///
+/// Rows scrolled out of view are despawned and the rows scrolled into view are spawned afresh, so nothing the callback inserted for one index lingers on the row of another. Mutating the [`VirtualList`] respawns all the spawned rows, which is how you refresh them when the underlying data changes.
+///
+/// The list manages the children of its node. Do not add children to it yourself.
+#[derive(Component, Clone)]
+#[require(Node, VirtualRows)]
+pub struct VirtualList {
+    /// Number of rows.
+    pub count: usize,
+    /// Height of the rows.
+    pub row_height: RowHeight,
+    /// Number of rows spawned beyond each end of the viewport.
+    pub overscan: usize,
+    /// Callback inserting the content of the row of the given index.
+    spawn_row: Arc<dyn Fn(usize, &mut EntityCommands) + Send + Sync>,
+}
+
+impl VirtualList {
+    /// Default value of [`VirtualList::overscan`].
+    pub const DEFAULT_OVERSCAN: usize = 2;
+
+    /// Creates a [`VirtualList`] of `count` rows whose content is inserted by `spawn_row`.
+    pub fn new(
+        count: usize,
+        row_height: RowHeight,
+        spawn_row: impl Fn(usize, &mut EntityCommands) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            count,
+            row_height,
+            overscan: Self::DEFAULT_OVERSCAN,
+            spawn_row: Arc::new(spawn_row),
+        }
+    }
+
+    /// Sets the number of rows spawned beyond each end of the viewport.
+    pub fn with_overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+}
+
+impl fmt::Debug for VirtualList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VirtualList")
+            .field("count", &self.count)
+            .field("row_height", &self.row_height)
+            .field("overscan", &self.overscan)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Height of the rows of a [`VirtualList`] in logical pixels.
+#[derive(Copy, Clone, PartialEq, Reflect, Debug)]
+pub enum RowHeight {
+    /// Every row has this height.
+    Fixed(f32),
+    /// Rows are sized by their content. This height is assumed until a row is spawned and measured.
+    Estimated(f32),
+}
+
+impl RowHeight {
+    fn px(&self) -> f32 {
+        match *self {
+            RowHeight::Fixed(px) | RowHeight::Estimated(px) => px,
+        }
+    }
+}
+
+/// Component of a row spawned by a [`VirtualList`].
+#[derive(Component, Copy, Clone, Reflect, Debug)]
+pub struct VirtualRow {
+    /// The [`VirtualList`] entity of this row.
+    list: Entity,
+    /// The index of this row in the list.
+    index: usize,
+}
+
+impl VirtualRow {
+    /// Gets the [`VirtualList`] entity of this row.
+    pub fn list(&self) -> Entity {
+        self.list
+    }
+
+    /// Gets the index of this row in the list.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// Component of a [`VirtualList`] node tracking its spawned rows.
+#[derive(Component, Default, Clone, Debug)]
+pub(crate) struct VirtualRows {
+    /// Child node as tall as all the rows, parent of the spawned rows.
+    content: Option<Entity>,
+    /// Height of each row in logical pixels.
+    heights: Vec<f32>,
+    /// Offset of each row from the top of the list in logical pixels, followed by the length of the list.
+    offsets: Vec<f32>,
+    /// Spawned rows by index.
+    rows: HashMap<usize, Entity>,
+}
+
+impl VirtualRows {
+    fn length(&self) -> f32 {
+        self.offsets.last().copied().unwrap_or_default()
+    }
+}
+
+/// Spawns the rows of [`VirtualList`]s intersecting their viewport and despawns the others.
+///
+/// This system runs in `PostUpdate` before `UiSystems::Layout` so rows are laid out in the frame they are spawned. The viewport is read from the layout of the previous frame.
+pub(crate) fn update_virtual_lists(
+    mut q_list: Query<(
+        Entity,
+        Ref<VirtualList>,
+        &mut VirtualRows,
+        &ScrollPosition,
+        &ComputedNode,
+    )>,
+    q_row: Query<&ComputedNode, With<VirtualRow>>,
+    mut q_node: Query<&mut Node, Without<VirtualList>>,
+    mut commands: Commands,
+) {
+    for (entity, list, mut virtual_rows, scroll_position, cnode) in &mut q_list {
+        let virtual_rows = &mut *virtual_rows;
+        let content = *virtual_rows.content.get_or_insert_with(|| {
+            commands
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        flex_shrink: 0.0,
+                        ..default()
+                    },
+                    ChildOf(entity),
+                ))
+                .id()
+        });
+
+        // Resize the list and measure rows sized by their content
+        let mut resized = virtual_rows.heights.len() != list.count
+            || match list.row_height {
+                RowHeight::Fixed(px) => virtual_rows.heights.first().is_some_and(|&h| h != px),
+                RowHeight::Estimated(_) => false,
+            };
+        if resized {
+            virtual_rows.heights = vec![list.row_height.px(); list.count];
+        } else if let RowHeight::Estimated(_) = list.row_height {
+            for (&index, &row) in &virtual_rows.rows {
+                let Ok(row_cnode) = q_row.get(row) else {
+                    continue;
+                };
+                let height = row_cnode.inverse_scale_factor * row_cnode.size.y;
+                if height > 0.0 && (height - virtual_rows.heights[index]).abs() >= 0.5 {
+                    virtual_rows.heights[index] = height;
+                    resized = true;
+                }
+            }
+        }
+        if resized || list.is_added() {
+            virtual_rows.offsets = std::iter::once(0.0)
+                .chain(virtual_rows.heights.iter().scan(0.0, |offset, height| {
+                    *offset += height;
+                    Some(*offset)
+                }))
+                .collect();
+            commands.entity(content).insert(Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(virtual_rows.length()),
+                flex_shrink: 0.0,
+                ..default()
+            });
+        }
+
+        // Compute the range of rows intersecting the viewport
+        let viewport_start = scroll_position.y;
+        let viewport_end = viewport_start + cnode.inverse_scale_factor * cnode.size.y;
+        let offsets = &virtual_rows.offsets;
+        let first = offsets[1..].partition_point(|&end| end <= viewport_start);
+        let last = offsets[..offsets.len() - 1].partition_point(|&start| start < viewport_end);
+        let visible = first.saturating_sub(list.overscan)..(last + list.overscan).min(list.count);
+
+        // Despawn the rows out of the range, or all of them if the list changed
+        let refresh = list.is_changed() && !list.is_added();
+        virtual_rows.rows.retain(|index, &mut row| {
+            let keep = !refresh && visible.contains(index);
+            if !keep {
+                commands.entity(row).despawn();
+            }
+            keep
+        });
+
+        // Reposition the kept rows if their offsets changed
+        if resized {
+            for (&index, &row) in &virtual_rows.rows {
+                if let Ok(mut node) = q_node.get_mut(row) {
+                    node.top = Val::Px(virtual_rows.offsets[index]);
+                }
+            }
+        }
+
+        // Spawn the missing rows
+        for index in visible {
+            if virtual_rows.rows.contains_key(&index) {
+                continue;
+            }
+            let node = Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(virtual_rows.offsets[index]),
+                width: Val::Percent(100.0),
+                height: match list.row_height {
+                    RowHeight::Fixed(px) => Val::Px(px),
+                    RowHeight::Estimated(_) => Val::Auto,
+                },
+                ..default()
+            };
+            let mut row = commands.spawn((
+                node,
+                VirtualRow {
+                    list: entity,
+                    index,
+                },
+                ChildOf(content),
+            ));
+            (list.spawn_row)(index, &mut row);
+            virtual_rows.rows.insert(index, row.id());
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+
+use crate::{
+    Scrollable,
+    scrollable::{content_size, max_offset},
+};
+
+/// Component of a [`Scrollable`] node whose content wraps around, e.g. a carousel.
+///
+/// Scrolling past the end of the content continues seamlessly from its start and the other way around. To do so, children scrolled out of the viewport are moved from one end of the content to the other, and the thumb of the [`Scrollbar`](crate::Scrollbar) wraps from one end of the track to the other instead of stopping. The thumb then travels the track once per full turn of the content, and clicking the trough scrolls to that fraction of a turn. [`ScrollProgress`](crate::ScrollProgress) likewise holds the fraction of a turn scrolled.
+///
+/// The content must be at least as long as the viewport plus its longest child to wrap seamlessly, so duplicate the children of shorter content. Children should be spaced with margins rather than gaps, so the content is as long as one turn.
+#[derive(Component, Default, Copy, Clone, Reflect, Debug)]
+pub struct WrapAround {
+    /// Length in logical pixels of the children moved from the start to the end of the content, modulo the content length.
+    pub(crate) offset: f32,
+}
+
+impl WrapAround {
+    /// Returns how far in logical pixels the children were moved from the start to the end of the content, between zero and the content length.
+    ///
+    /// The [`ScrollPosition`] of the node plus this offset is the position of the viewport in the content as it was first laid out.
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Returns the position along the content of the start of the viewport as a fraction of a turn of content `period` logical pixels long.
+    pub(crate) fn progress(&self, position: f32, period: f32) -> f32 {
+        if period > 0.0 {
+            ((position + self.offset) / period).rem_euclid(1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Moves the children of [`WrapAround`] nodes scrolled past either end of their content to the other end.
+///
+/// This system runs in `PostUpdate` before `UiSystems::Layout` so the moved children are laid out in the same frame. Children sizes are read from the layout of the previous frame.
+pub(crate) fn wrap_content_around(
+    mut q_scrollable: Query<
+        (
+            &mut WrapAround,
+            &mut ScrollPosition,
+            &Node,
+            &ComputedNode,
+            &mut Children,
+        ),
+        With<Scrollable>,
+    >,
+    q_child: Query<(&ComputedNode, &UiGlobalTransform)>,
+) {
+    for (mut wrap, mut scroll_position, node, cnode, mut children) in &mut q_scrollable {
+        let Some(axis) = crate::scroll_axis(node) else {
+            continue;
+        };
+        let period = cnode.inverse_scale_factor * content_size(cnode, None)[axis];
+        let max_offset = max_offset(cnode, None)[axis];
+        let position = scroll_position[axis];
+        if max_offset <= 0.0 || (0.0..=max_offset).contains(&position) {
+            continue;
+        }
+
+        // Start of each child along the content in logical pixels
+        let starts = children
+            .iter()
+            .filter_map(|child| q_child.get(child).ok())
+            .map(|(child_cnode, child_transform)| {
+                child_cnode.inverse_scale_factor
+                    * (child_transform.translation[axis] - child_cnode.size[axis] / 2.0)
+            })
+            .collect::<Vec<_>>();
+        if starts.len() != children.len() {
+            continue;
+        }
+        // Distance from each child to the next one, the last one being followed by the first one of the next turn
+        let steps = starts
+            .iter()
+            .zip(starts.iter().skip(1).chain([&(starts[0] + period)]))
+            .map(|(start, next_start)| next_start - start)
+            .collect::<Vec<_>>();
+
+        // A whole turn leaves the content as it is, so only children at the start need moving to the end
+        let mut position = position.rem_euclid(period);
+        let mut moved = 0;
+        for &step in &steps {
+            if position <= max_offset || step <= 0.0 || step > position {
+                break;
+            }
+            position -= step;
+            wrap.offset = (wrap.offset + step).rem_euclid(period);
+            moved += 1;
+        }
+        if moved > 0 {
+            rotate_left(&mut children, moved);
+        }
+        scroll_position[axis] = position;
+    }
+}
+
+/// Moves the first `mid` children to the end, keeping their order.
+fn rotate_left(children: &mut Children, mid: usize) {
+    let len = children.len();
+    if mid == 0 || mid >= len {
+        return;
+    }
+    reverse(children, 0, mid);
+    reverse(children, mid, len);
+    reverse(children, 0, len);
+}
+
+/// Reverses the order of the children from `start` included to `end` excluded.
+fn reverse(children: &mut Children, start: usize, end: usize) {
+    let (mut a, mut b) = (start, end);
+    while a + 1 < b {
+        b -= 1;
+        children.swap(a, b);
+        a += 1;
+    }
+}
@@ -0,0 +1,182 @@
+//! Headless app and scrollbar shared by the tests.
+//!
+//! [`spawn_scrollbar`] lays out a 100x100 scrollable node holding ten 50 pixels long children next to a 10 pixels thick scrollbar. The content is 500 pixels long, so it scrolls over 400 pixels and the thumb is 20 pixels long, leaving 80 pixels for it to move along the track.
+
+#![allow(dead_code)]
+
+use bevy::{
+    camera::{ComputedCameraValues, RenderTargetInfo, Viewport},
+    ecs::spawn::SpawnIter,
+    prelude::*,
+};
+use bevy_scrollbar::{Scrollable, ScrollbarPlugin, Thumb};
+
+pub const TARGET_SIZE: UVec2 = UVec2::new(1000, 1000);
+
+/// Scrolling direction of a test.
+#[derive(Copy, Clone, Debug)]
+pub enum Direction {
+    Vertical,
+    Horizontal,
+}
+
+impl Direction {
+    /// Index of the scrolling axis in a `Vec2`.
+    pub fn axis(self) -> usize {
+        match self {
+            Direction::Vertical => 1,
+            Direction::Horizontal => 0,
+        }
+    }
+
+    /// Returns a `Vec2` holding `value` along the scrolling axis.
+    pub fn along(self, value: f32) -> Vec2 {
+        match self {
+            Direction::Vertical => Vec2::new(0.0, value),
+            Direction::Horizontal => Vec2::new(value, 0.0),
+        }
+    }
+}
+
+/// Entities of a scrollbar spawned by [`spawn_scrollbar`].
+pub struct Entities {
+    pub scrollable: Entity,
+    pub scrollbar: Entity,
+    pub thumb: Entity,
+}
+
+/// Creates a headless app laying out UI nodes on a 1000x1000 render target.
+pub fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        ImagePlugin::default(),
+        bevy::input::InputPlugin,
+        bevy::picking::PickingPlugin,
+        bevy::picking::InteractionPlugin,
+        bevy::text::TextPlugin,
+        TransformPlugin,
+        bevy::ui::UiPlugin,
+        ScrollbarPlugin,
+    ))
+    .init_asset::<TextureAtlasLayout>();
+    app.world_mut().spawn((
+        Camera2d,
+        Camera {
+            computed: ComputedCameraValues {
+                target_info: Some(RenderTargetInfo {
+                    physical_size: TARGET_SIZE,
+                    scale_factor: 1.0,
+                }),
+                ..default()
+            },
+            viewport: Some(Viewport {
+                physical_size: TARGET_SIZE,
+                ..default()
+            }),
+            ..default()
+        },
+    ));
+    app
+}
+
+/// Spawns a scrollable node and its scrollbar, then lays them out.
+pub fn spawn_scrollbar(app: &mut App, direction: Direction) -> Entities {
+    spawn_scrollbar_with(app, direction, ())
+}
+
+/// Spawns a scrollable node and its scrollbar with `bundle` inserted on the scrollbar, then lays them out.
+pub fn spawn_scrollbar_with(app: &mut App, direction: Direction, bundle: impl Bundle) -> Entities {
+    spawn(app, direction, (), bundle)
+}
+
+/// Spawns a scrollable node with `bundle` inserted on it and its scrollbar, then lays them out.
+pub fn spawn_scrollable_with(app: &mut App, direction: Direction, bundle: impl Bundle) -> Entities {
+    spawn(app, direction, bundle, ())
+}
+
+/// Spawns a scrollable node and its scrollbar with `scrollable_bundle` and `scrollbar_bundle` inserted on them, then lays them out.
+fn spawn(
+    app: &mut App,
+    direction: Direction,
+    scrollable_bundle: impl Bundle,
+    scrollbar_bundle: impl Bundle,
+) -> Entities {
+    let (overflow, flex_direction, child, track) = match direction {
+        Direction::Vertical => (
+            Overflow::scroll_y(),
+            FlexDirection::Column,
+            Node {
+                height: Val::Px(50.0),
+                flex_shrink: 0.0,
+                ..default()
+            },
+            Node {
+                width: Val::Px(10.0),
+                height: Val::Px(100.0),
+                ..default()
+            },
+        ),
+        Direction::Horizontal => (
+            Overflow::scroll_x(),
+            FlexDirection::Row,
+            Node {
+                width: Val::Px(50.0),
+                flex_shrink: 0.0,
+                ..default()
+            },
+            Node {
+                width: Val::Px(100.0),
+                height: Val::Px(10.0),
+                ..default()
+            },
+        ),
+    };
+    let scrollable = app
+        .world_mut()
+        .spawn((
+            Node {
+                width: Val::Px(100.0),
+                height: Val::Px(100.0),
+                overflow,
+                flex_direction,
+                ..default()
+            },
+            Children::spawn(SpawnIter(std::iter::repeat_n(child, 10))),
+            Scrollable::spawn_one((track, scrollbar_bundle)),
+            scrollable_bundle,
+        ))
+        .id();
+    // The thumb is spawned by a command, then laid out on the next frame
+    app.update();
+    app.update();
+
+    let scrollbar = app
+        .world()
+        .get::<Scrollable>(scrollable)
+        .unwrap()
+        .scrollbar();
+    let thumb = app.world().get::<Thumb>(scrollbar).unwrap().thumb();
+    Entities {
+        scrollable,
+        scrollbar,
+        thumb,
+    }
+}
+
+/// Returns the scroll position of the scrollable node along the scrolling axis.
+pub fn scroll_position(app: &App, entities: &Entities, direction: Direction) -> f32 {
+    app.world()
+        .get::<ScrollPosition>(entities.scrollable)
+        .unwrap()[direction.axis()]
+}
+
+/// Returns the offset of the thumb from the start of the track.
+pub fn thumb_offset(app: &App, entities: &Entities, direction: Direction) -> Val {
+    let node = app.world().get::<Node>(entities.thumb).unwrap();
+    match direction {
+        Direction::Vertical => node.top,
+        Direction::Horizontal => node.left,
+    }
+}
@@ -0,0 +1,1661 @@
+//! Headless tests of the scroll interactions of a scrollbar.
+
+mod common;
+
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use bevy::{
+    camera::NormalizedRenderTarget,
+    input::mouse::MouseScrollUnit,
+    picking::{
+        backend::HitData,
+        pointer::{Location, PointerAction, PointerButton, PointerId, PointerInput},
+    },
+    prelude::*,
+    sprite::{BorderRect, TextureSlicer},
+    time::TimeUpdateStrategy,
+    ui::widget::NodeImageMode,
+};
+use bevy_scrollbar::{
+    PageIndicator, Paged, RowHeight, ScrollLink, ScrollLinkMode, ScrollMetrics, ScrollProgress,
+    ScrollSnap, ScrollTable, ScrollTableBody, ScrollTableColumns, ScrollTableHeader,
+    ScrollTimeline, Scrollable, Scrollbar, ScrollbarPreLayoutSystems, ScrollbarSystems, SnapAlign,
+    SnapStrictness, StickyHeader, Thumb, ThumbColor, ThumbInset, ThumbOf, ThumbRadius, ThumbStyle,
+    VirtualContentSize, VirtualList, VirtualRow, WheelAcceleration, WrapAround,
+    testing::{simulate_click, simulate_thumb_drag, simulate_trough_click, simulate_wheel},
+};
+use common::{
+    Direction, Entities, app, scroll_position, spawn_scrollable_with, spawn_scrollbar,
+    spawn_scrollbar_with, thumb_offset,
+};
+
+/// Scrolls the mouse by `delta` pixels over `scrollable`.
+fn scroll(app: &mut App, scrollable: Entity, delta: Vec2) {
+    simulate_wheel(app.world_mut(), scrollable, delta);
+    app.update();
+}
+
+/// Drags the thumb of `scrollbar` by `delta` pixels.
+fn drag(app: &mut App, scrollbar: Entity, delta: Vec2) {
+    simulate_thumb_drag(app.world_mut(), scrollbar, delta).unwrap();
+    app.update();
+}
+
+/// Clicks the track of `scrollbar` at `fraction` of its length.
+fn click_trough(app: &mut App, scrollbar: Entity, fraction: f32) {
+    simulate_trough_click(app.world_mut(), scrollbar, fraction);
+    app.update();
+}
+
+/// Clicks `entity` at `position`, in normalized coordinates between -0.5 and 0.5.
+fn click(app: &mut App, entity: Entity, position: Vec2) {
+    simulate_click(app.world_mut(), entity, position);
+    app.update();
+}
+
+fn thumb_length(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+
+    let thumb_node = app.world().get::<Node>(entities.thumb).unwrap();
+    let length = match direction {
+        Direction::Vertical => thumb_node.height,
+        Direction::Horizontal => thumb_node.width,
+    };
+    assert_eq!(length, Val::Percent(20.0));
+    let thumb_cnode = app.world().get::<ComputedNode>(entities.thumb).unwrap();
+    assert_eq!(thumb_cnode.size[direction.axis()], 20.0);
+}
+
+#[test]
+fn thumb_length_vertical() {
+    thumb_length(Direction::Vertical);
+}
+
+#[test]
+fn thumb_length_horizontal() {
+    thumb_length(Direction::Horizontal);
+}
+
+fn wheel_scrolls_and_clamps(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+
+    // Scrolling the wheel down moves the content forward
+    scroll(&mut app, entities.scrollable, Vec2::splat(-30.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 30.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(6.0));
+
+    scroll(&mut app, entities.scrollable, Vec2::splat(-1000.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 400.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(80.0));
+
+    scroll(&mut app, entities.scrollable, Vec2::splat(1000.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 0.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::ZERO);
+}
+
+#[test]
+fn wheel_scrolls_and_clamps_vertical() {
+    wheel_scrolls_and_clamps(Direction::Vertical);
+}
+
+#[test]
+fn wheel_scrolls_and_clamps_horizontal() {
+    wheel_scrolls_and_clamps(Direction::Horizontal);
+}
+
+/// Scrolls the mouse wheel by `lines` notches over `scrollable`.
+fn scroll_lines(app: &mut App, scrollable: Entity, lines: f32) {
+    app.world_mut().trigger(Pointer::new(
+        PointerId::Mouse,
+        Location {
+            target: NormalizedRenderTarget::None {
+                width: 0,
+                height: 0,
+            },
+            position: Vec2::ZERO,
+        },
+        Scroll {
+            unit: MouseScrollUnit::Line,
+            x: lines,
+            y: lines,
+            hit: HitData::new(Entity::PLACEHOLDER, 0.0, None, None),
+        },
+        scrollable,
+    ));
+    app.update();
+}
+
+fn wheel_acceleration_starts_with_the_second_line_scroll(direction: Direction) {
+    let mut app = app();
+    let plain = spawn_scrollbar(&mut app, direction);
+    let accelerated = spawn_scrollable_with(&mut app, direction, WheelAcceleration::default());
+
+    // The first line scroll is not accelerated, however early in the life of the app
+    scroll_lines(&mut app, plain.scrollable, -1.0);
+    scroll_lines(&mut app, accelerated.scrollable, -1.0);
+    let line = scroll_position(&app, &plain, direction);
+    assert!(line > 0.0);
+    assert_eq!(scroll_position(&app, &accelerated, direction), line);
+
+    // The next one is
+    scroll_lines(&mut app, accelerated.scrollable, -1.0);
+    assert_eq!(scroll_position(&app, &accelerated, direction), 2.5 * line);
+}
+
+#[test]
+fn wheel_acceleration_starts_with_the_second_line_scroll_vertical() {
+    wheel_acceleration_starts_with_the_second_line_scroll(Direction::Vertical);
+}
+
+#[test]
+fn wheel_acceleration_starts_with_the_second_line_scroll_horizontal() {
+    wheel_acceleration_starts_with_the_second_line_scroll(Direction::Horizontal);
+}
+
+fn thumb_drag_scrolls_and_clamps(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+
+    // The content moves by DragSpeed::DEFAULT times the drag
+    drag(&mut app, entities.scrollbar, direction.along(5.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 20.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(4.0));
+
+    drag(&mut app, entities.scrollbar, direction.along(500.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 400.0);
+
+    drag(&mut app, entities.scrollbar, direction.along(-500.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 0.0);
+}
+
+#[test]
+fn thumb_drag_scrolls_and_clamps_vertical() {
+    thumb_drag_scrolls_and_clamps(Direction::Vertical);
+}
+
+#[test]
+fn thumb_drag_scrolls_and_clamps_horizontal() {
+    thumb_drag_scrolls_and_clamps(Direction::Horizontal);
+}
+
+fn trough_click_jumps(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+
+    // Clicking the middle of the track centers the thumb on it
+    click_trough(&mut app, entities.scrollbar, 0.5);
+    assert_eq!(scroll_position(&app, &entities, direction), 200.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(40.0));
+
+    // Clicks near the ends of the track move the thumb as far as it goes
+    click_trough(&mut app, entities.scrollbar, 0.99);
+    assert_eq!(scroll_position(&app, &entities, direction), 400.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(80.0));
+
+    click_trough(&mut app, entities.scrollbar, 0.01);
+    assert_eq!(scroll_position(&app, &entities, direction), 0.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::ZERO);
+}
+
+#[test]
+fn trough_click_jumps_vertical() {
+    trough_click_jumps(Direction::Vertical);
+}
+
+#[test]
+fn trough_click_jumps_horizontal() {
+    trough_click_jumps(Direction::Horizontal);
+}
+
+fn thumb_click_is_ignored(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+
+    // The click propagates from the thumb to the track, which discards it
+    click(&mut app, entities.thumb, Vec2::splat(0.49));
+    assert_eq!(scroll_position(&app, &entities, direction), 0.0);
+}
+
+#[test]
+fn thumb_click_is_ignored_vertical() {
+    thumb_click_is_ignored(Direction::Vertical);
+}
+
+#[test]
+fn thumb_click_is_ignored_horizontal() {
+    thumb_click_is_ignored(Direction::Horizontal);
+}
+
+fn thumb_is_placed_in_the_same_frame(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+
+    // The thumb is drawn where its new offset puts it without waiting for the next layout
+    scroll(&mut app, entities.scrollable, Vec2::splat(-30.0));
+    let transform = app
+        .world()
+        .get::<UiGlobalTransform>(entities.thumb)
+        .unwrap();
+    assert_eq!(transform.translation[direction.axis()], 16.0);
+}
+
+#[test]
+fn thumb_is_placed_in_the_same_frame_vertical() {
+    thumb_is_placed_in_the_same_frame(Direction::Vertical);
+}
+
+#[test]
+fn thumb_is_placed_in_the_same_frame_horizontal() {
+    thumb_is_placed_in_the_same_frame(Direction::Horizontal);
+}
+
+fn empty_content_fills_the_track(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+    app.world_mut()
+        .entity_mut(entities.scrollable)
+        .despawn_related::<Children>();
+    app.update();
+    app.update();
+
+    let metrics = app
+        .world()
+        .get::<ScrollMetrics>(entities.scrollable)
+        .unwrap();
+    assert!(!metrics.is_overflowing());
+    let thumb_node = app.world().get::<Node>(entities.thumb).unwrap();
+    let length = match direction {
+        Direction::Vertical => thumb_node.height,
+        Direction::Horizontal => thumb_node.width,
+    };
+    assert_eq!(length, Val::Percent(100.0));
+
+    // Neither scrolling nor clicking the track moves the content
+    scroll(&mut app, entities.scrollable, Vec2::splat(-30.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 0.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::ZERO);
+    click_trough(&mut app, entities.scrollbar, 0.99);
+    assert_eq!(scroll_position(&app, &entities, direction), 0.0);
+}
+
+#[test]
+fn empty_content_fills_the_track_vertical() {
+    empty_content_fills_the_track(Direction::Vertical);
+}
+
+#[test]
+fn empty_content_fills_the_track_horizontal() {
+    empty_content_fills_the_track(Direction::Horizontal);
+}
+
+fn custom_thumb_is_used(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar_with(
+        &mut app,
+        direction,
+        Thumb::spawn_one((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            Children::spawn_one(Node {
+                width: Val::Px(2.0),
+                height: Val::Px(2.0),
+                ..default()
+            }),
+        )),
+    );
+
+    // The scrollbar neither spawns a thumb of its own nor takes the child of the thumb for it
+    let children = app.world().get::<Children>(entities.scrollbar).unwrap();
+    assert_eq!(**children, [entities.thumb]);
+    let thumb_of = app.world().get::<ThumbOf>(entities.thumb).unwrap();
+    assert_eq!(thumb_of.scrollbar, entities.scrollbar);
+    let thumb_cnode = app.world().get::<ComputedNode>(entities.thumb).unwrap();
+    assert_eq!(thumb_cnode.size[direction.axis()], 20.0);
+
+    drag(&mut app, entities.scrollbar, direction.along(5.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 20.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(4.0));
+}
+
+#[test]
+fn custom_thumb_is_used_vertical() {
+    custom_thumb_is_used(Direction::Vertical);
+}
+
+#[test]
+fn custom_thumb_is_used_horizontal() {
+    custom_thumb_is_used(Direction::Horizontal);
+}
+
+fn custom_thumb_can_be_moved_across_the_track(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar_with(&mut app, direction, Thumb::spawn_one(Node::default()));
+    // Offsets across the track of the thumb, from its start and from its end
+    let cross_offsets = |node: &Node| match direction {
+        Direction::Vertical => (node.left, node.right),
+        Direction::Horizontal => (node.top, node.bottom),
+    };
+    let node = app.world().get::<Node>(entities.thumb).unwrap();
+    assert_eq!(cross_offsets(node), (Val::Px(0.0), Val::Px(0.0)));
+
+    // A narrower thumb moved across the track by the user stays there
+    let mut node = app.world_mut().get_mut::<Node>(entities.thumb).unwrap();
+    match direction {
+        Direction::Vertical => {
+            (node.width, node.left, node.right) = (Val::Px(4.0), Val::Px(3.0), Val::Auto)
+        }
+        Direction::Horizontal => {
+            (node.height, node.top, node.bottom) = (Val::Px(4.0), Val::Px(3.0), Val::Auto)
+        }
+    }
+    app.update();
+    app.update();
+    let node = app.world().get::<Node>(entities.thumb).unwrap();
+    assert_eq!(cross_offsets(node), (Val::Px(3.0), Val::Auto));
+    let cross_axis = 1 - direction.axis();
+    let thumb_cnode = app.world().get::<ComputedNode>(entities.thumb).unwrap();
+    assert_eq!(thumb_cnode.size[cross_axis], 4.0);
+
+    // Until the inset changes
+    app.world_mut()
+        .entity_mut(entities.scrollbar)
+        .insert(ThumbInset(1.0));
+    app.update();
+    let node = app.world().get::<Node>(entities.thumb).unwrap();
+    assert_eq!(cross_offsets(node), (Val::Px(1.0), Val::Px(1.0)));
+}
+
+#[test]
+fn custom_thumb_can_be_moved_across_the_track_vertical() {
+    custom_thumb_can_be_moved_across_the_track(Direction::Vertical);
+}
+
+#[test]
+fn custom_thumb_can_be_moved_across_the_track_horizontal() {
+    custom_thumb_can_be_moved_across_the_track(Direction::Horizontal);
+}
+
+fn track_hosts_other_children(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+    let marker = Node {
+        width: Val::Px(10.0),
+        height: Val::Px(10.0),
+        flex_shrink: 0.0,
+        ..default()
+    };
+    let markers = [
+        app.world_mut().spawn(marker.clone()).id(),
+        app.world_mut().spawn(marker).id(),
+    ];
+    app.world_mut()
+        .entity_mut(entities.scrollbar)
+        .insert_children(0, &markers);
+    app.update();
+
+    // The thumb is found past the markers and keeps its length
+    let children = app.world().get::<Children>(entities.scrollbar).unwrap();
+    assert_eq!(**children, [markers[0], markers[1], entities.thumb]);
+    let thumb_cnode = app.world().get::<ComputedNode>(entities.thumb).unwrap();
+    assert_eq!(thumb_cnode.size[direction.axis()], 20.0);
+
+    // Nor do the markers move the thumb
+    click_trough(&mut app, entities.scrollbar, 0.5);
+    assert_eq!(scroll_position(&app, &entities, direction), 200.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(40.0));
+    app.update();
+    let transform = app
+        .world()
+        .get::<UiGlobalTransform>(entities.thumb)
+        .unwrap();
+    assert_eq!(transform.translation[direction.axis()], 50.0);
+}
+
+#[test]
+fn track_hosts_other_children_vertical() {
+    track_hosts_other_children(Direction::Vertical);
+}
+
+#[test]
+fn track_hosts_other_children_horizontal() {
+    track_hosts_other_children(Direction::Horizontal);
+}
+
+fn scrollbar_is_torn_down_and_set_up_again(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+
+    // Removing the scrollbar despawns its thumb and stops the wheel from scrolling the content
+    app.world_mut()
+        .entity_mut(entities.scrollbar)
+        .remove::<Scrollbar>();
+    app.update();
+    assert!(app.world().get_entity(entities.thumb).is_err());
+    assert!(app.world().get::<Scrollable>(entities.scrollable).is_none());
+    scroll(&mut app, entities.scrollable, Vec2::splat(-30.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 0.0);
+
+    // Adding it back scrolls the content once per wheel scroll
+    app.world_mut()
+        .entity_mut(entities.scrollbar)
+        .insert(Scrollbar {
+            scrollable: entities.scrollable,
+        });
+    app.update();
+    app.update();
+    let thumb = app
+        .world()
+        .get::<Thumb>(entities.scrollbar)
+        .unwrap()
+        .thumb();
+    let entities = Entities { thumb, ..entities };
+    scroll(&mut app, entities.scrollable, Vec2::splat(-30.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 30.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(6.0));
+
+    // Despawning the scrollbar leaves the content alone
+    app.world_mut().entity_mut(entities.scrollbar).despawn();
+    app.update();
+    assert!(app.world().get_entity(entities.thumb).is_err());
+    scroll(&mut app, entities.scrollable, Vec2::splat(-30.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 30.0);
+}
+
+#[test]
+fn scrollbar_is_torn_down_and_set_up_again_vertical() {
+    scrollbar_is_torn_down_and_set_up_again(Direction::Vertical);
+}
+
+#[test]
+fn scrollbar_is_torn_down_and_set_up_again_horizontal() {
+    scrollbar_is_torn_down_and_set_up_again(Direction::Horizontal);
+}
+
+#[test]
+fn scrollbar_is_retargeted() {
+    let mut app = app();
+    let vertical = spawn_scrollbar(&mut app, Direction::Vertical);
+    let horizontal = spawn_scrollbar(&mut app, Direction::Horizontal);
+    app.world_mut().entity_mut(horizontal.scrollbar).despawn();
+
+    // Point the vertical scrollbar at the horizontal scrollable node
+    app.world_mut()
+        .entity_mut(vertical.scrollbar)
+        .insert(Scrollbar {
+            scrollable: horizontal.scrollable,
+        });
+    app.update();
+    app.update();
+    let entities = Entities {
+        scrollable: horizontal.scrollable,
+        ..vertical
+    };
+
+    // The same thumb is turned along the new direction
+    let thumb = app
+        .world()
+        .get::<Thumb>(entities.scrollbar)
+        .unwrap()
+        .thumb();
+    assert_eq!(thumb, entities.thumb);
+    let thumb_node = app.world().get::<Node>(entities.thumb).unwrap();
+    assert_eq!(thumb_node.width, Val::Percent(20.0));
+    // It spans the track across, which is 100 pixels tall
+    let thumb_cnode = app.world().get::<ComputedNode>(entities.thumb).unwrap();
+    assert_eq!(thumb_cnode.size.y, 100.0);
+
+    // Only the new scrollable node is scrolled by the wheel
+    scroll(&mut app, vertical.scrollable, Vec2::splat(-30.0));
+    assert_eq!(scroll_position(&app, &vertical, Direction::Vertical), 0.0);
+    scroll(&mut app, entities.scrollable, Vec2::splat(-30.0));
+    assert_eq!(
+        scroll_position(&app, &entities, Direction::Horizontal),
+        30.0
+    );
+    assert!(app.world().get::<Scrollable>(vertical.scrollable).is_none());
+}
+
+#[test]
+fn thumb_follows_overflow_axis() {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, Direction::Vertical);
+
+    // Lay the content out horizontally
+    let mut node = app
+        .world_mut()
+        .get_mut::<Node>(entities.scrollable)
+        .unwrap();
+    node.overflow = Overflow::scroll_x();
+    node.flex_direction = FlexDirection::Row;
+    let children = app
+        .world()
+        .get::<Children>(entities.scrollable)
+        .unwrap()
+        .to_vec();
+    for child in children {
+        app.world_mut().get_mut::<Node>(child).unwrap().width = Val::Px(50.0);
+    }
+    app.update();
+
+    let thumb_node = app.world().get::<Node>(entities.thumb).unwrap();
+    assert_eq!(thumb_node.width, Val::Percent(20.0));
+    let thumb_cnode = app.world().get::<ComputedNode>(entities.thumb).unwrap();
+    assert_eq!(thumb_cnode.size.y, 100.0);
+    scroll(&mut app, entities.scrollable, Vec2::splat(-30.0));
+    assert_eq!(
+        scroll_position(&app, &entities, Direction::Horizontal),
+        30.0
+    );
+}
+
+#[test]
+fn thumb_style_is_propagated() {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, Direction::Vertical);
+    let thumb_cnode = app.world().get::<ComputedNode>(entities.thumb).unwrap();
+    assert_eq!(thumb_cnode.size.x, 10.0);
+
+    app.world_mut().entity_mut(entities.scrollbar).insert((
+        ThumbColor(Color::srgb(1.0, 0.0, 0.0)),
+        ThumbRadius(BorderRadius::all(Val::Px(3.0))),
+        ThumbInset(2.0),
+    ));
+    app.update();
+
+    let thumb = app.world().entity(entities.thumb);
+    assert_eq!(
+        thumb.get::<BackgroundColor>().unwrap().0,
+        Color::srgb(1.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        thumb.get::<Node>().unwrap().border_radius,
+        BorderRadius::all(Val::Px(3.0))
+    );
+    assert_eq!(thumb.get::<ComputedNode>().unwrap().size.x, 6.0);
+}
+
+fn thumb_style_is_applied(direction: Direction) {
+    let mut app = app();
+    let image = Handle::<Image>::default();
+    let slicer = TextureSlicer {
+        border: BorderRect::all(4.0),
+        ..default()
+    };
+    let style = ThumbStyle::default()
+        .with_image(image.clone())
+        .with_slicer(slicer.clone())
+        .with_border(UiRect::all(Val::Px(1.0)), Color::BLACK)
+        .with_outline(Outline::new(Val::Px(2.0), Val::ZERO, Color::WHITE))
+        .with_grip(image.clone(), Vec2::splat(6.0));
+    let color = Color::srgb(1.0, 0.0, 0.0);
+    let entities = spawn_scrollbar_with(&mut app, direction, (style, ThumbColor(color)));
+
+    // The image of the thumb is sliced and tinted by its color instead of being filled with it
+    let thumb = app.world().entity(entities.thumb);
+    let image_node = thumb.get::<ImageNode>().unwrap();
+    assert_eq!(image_node.image, image);
+    assert_eq!(image_node.color, color);
+    assert!(
+        matches!(&image_node.image_mode, NodeImageMode::Sliced(thumb_slicer) if thumb_slicer.border == slicer.border)
+    );
+    assert_eq!(
+        thumb.get::<BackgroundColor>(),
+        Some(&BackgroundColor::DEFAULT)
+    );
+    assert_eq!(
+        thumb.get::<Node>().unwrap().border,
+        UiRect::all(Val::Px(1.0))
+    );
+    assert_eq!(
+        thumb.get::<BorderColor>(),
+        Some(&BorderColor::all(Color::BLACK))
+    );
+    let outline = thumb.get::<Outline>().unwrap();
+    assert_eq!(outline.width, Val::Px(2.0));
+    assert_eq!(outline.color, Color::WHITE);
+
+    // The grip is the only child of the thumb and is centered on it
+    let children = thumb.get::<Children>().unwrap().to_vec();
+    assert_eq!(children.len(), 1);
+    let grip = app.world().entity(children[0]);
+    assert_eq!(grip.get::<ImageNode>().unwrap().image, image);
+    assert_eq!(grip.get::<ComputedNode>().unwrap().size, Vec2::splat(6.0));
+    let thumb_center = thumb.get::<UiGlobalTransform>().unwrap().translation;
+    let grip_center = grip.get::<UiGlobalTransform>().unwrap().translation;
+    assert_eq!(grip_center, thumb_center);
+}
+
+#[test]
+fn thumb_style_is_applied_vertical() {
+    thumb_style_is_applied(Direction::Vertical);
+}
+
+#[test]
+fn thumb_style_is_applied_horizontal() {
+    thumb_style_is_applied(Direction::Horizontal);
+}
+
+/// Resizes the children of the scrollable node to `length` pixels along the scrolling axis, then lays them out.
+fn resize_children(app: &mut App, entities: &Entities, direction: Direction, length: f32) {
+    let children = app
+        .world()
+        .get::<Children>(entities.scrollable)
+        .unwrap()
+        .to_vec();
+    for child in children {
+        let mut node = app.world_mut().get_mut::<Node>(child).unwrap();
+        match direction {
+            Direction::Vertical => node.height = Val::Px(length),
+            Direction::Horizontal => node.width = Val::Px(length),
+        }
+    }
+    app.update();
+}
+
+/// Presses or releases the primary button of the mouse pointer.
+fn press_mouse(app: &mut App, action: PointerAction) {
+    let location = Location {
+        target: NormalizedRenderTarget::None {
+            width: 0,
+            height: 0,
+        },
+        position: Vec2::ZERO,
+    };
+    app.world_mut()
+        .write_message(PointerInput::new(PointerId::Mouse, location, action));
+}
+
+/// Spawns a scrollbar over 40 pixels long children, scrolls it by 35 pixels and adds `snap` to it.
+fn spawn_snapping_scrollbar(app: &mut App, direction: Direction, snap: ScrollSnap) -> Entities {
+    // A second per frame lets the snapping animation settle in a few frames
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+    let entities = spawn_scrollbar(app, direction);
+    resize_children(app, &entities, direction, 40.0);
+    scroll(app, entities.scrollable, Vec2::splat(-35.0));
+    app.world_mut().entity_mut(entities.scrollable).insert(snap);
+    entities
+}
+
+/// Returns where content snapping with `snap` settles after being scrolled by 35 pixels.
+fn snapped_position(direction: Direction, snap: ScrollSnap) -> f32 {
+    let mut app = app();
+    let entities = spawn_snapping_scrollbar(&mut app, direction, snap);
+    for _ in 0..5 {
+        app.update();
+    }
+    scroll_position(&app, &entities, direction)
+}
+
+fn content_snaps_to_children(direction: Direction) {
+    // Children start every 40 pixels and the viewport is 100 pixels long
+    assert_eq!(
+        snapped_position(direction, ScrollSnap::new(SnapAlign::Start)),
+        40.0
+    );
+    assert_eq!(
+        snapped_position(direction, ScrollSnap::new(SnapAlign::Center)),
+        50.0
+    );
+    assert_eq!(
+        snapped_position(direction, ScrollSnap::new(SnapAlign::End)),
+        20.0
+    );
+
+    // Proximity snapping leaves the content alone away from the snap points
+    let proximity = |distance| {
+        ScrollSnap::new(SnapAlign::Start).with_strictness(SnapStrictness::Proximity(distance))
+    };
+    assert_eq!(snapped_position(direction, proximity(2.0)), 35.0);
+    assert_eq!(snapped_position(direction, proximity(10.0)), 40.0);
+}
+
+#[test]
+fn content_snaps_to_children_vertical() {
+    content_snaps_to_children(Direction::Vertical);
+}
+
+#[test]
+fn content_snaps_to_children_horizontal() {
+    content_snaps_to_children(Direction::Horizontal);
+}
+
+/// Presses the primary mouse button on `entity`, from which the press bubbles up.
+fn press_on(app: &mut App, entity: Entity) {
+    press_mouse(app, PointerAction::Press(PointerButton::Primary));
+    app.world_mut().trigger(Pointer::new(
+        PointerId::Mouse,
+        Location {
+            target: NormalizedRenderTarget::None {
+                width: 0,
+                height: 0,
+            },
+            position: Vec2::ZERO,
+        },
+        Press {
+            button: PointerButton::Primary,
+            hit: HitData::new(Entity::PLACEHOLDER, 0.0, None, None),
+        },
+        entity,
+    ));
+}
+
+fn content_does_not_snap_while_pressed(direction: Direction) {
+    let mut app = app();
+    app.world_mut().spawn(PointerId::Mouse);
+    let entities = spawn_snapping_scrollbar(&mut app, direction, ScrollSnap::default());
+    let child = app.world().get::<Children>(entities.scrollable).unwrap()[0];
+
+    // Pressing the content holds the snapping until the button is released
+    press_on(&mut app, child);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(scroll_position(&app, &entities, direction), 35.0);
+    press_mouse(&mut app, PointerAction::Release(PointerButton::Primary));
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(scroll_position(&app, &entities, direction), 40.0);
+
+    // So does pressing the thumb
+    scroll(&mut app, entities.scrollable, Vec2::splat(-35.0));
+    press_on(&mut app, entities.thumb);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(scroll_position(&app, &entities, direction), 75.0);
+    press_mouse(&mut app, PointerAction::Release(PointerButton::Primary));
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(scroll_position(&app, &entities, direction), 80.0);
+}
+
+#[test]
+fn content_does_not_snap_while_pressed_vertical() {
+    content_does_not_snap_while_pressed(Direction::Vertical);
+}
+
+#[test]
+fn content_does_not_snap_while_pressed_horizontal() {
+    content_does_not_snap_while_pressed(Direction::Horizontal);
+}
+
+fn content_snaps_while_pressed_elsewhere(direction: Direction) {
+    let mut app = app();
+    app.world_mut().spawn(PointerId::Mouse);
+    let slider = app.world_mut().spawn(Node::default()).id();
+    let entities = spawn_snapping_scrollbar(&mut app, direction, ScrollSnap::default());
+    press_on(&mut app, slider);
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(scroll_position(&app, &entities, direction), 40.0);
+}
+
+#[test]
+fn content_snaps_while_pressed_elsewhere_vertical() {
+    content_snaps_while_pressed_elsewhere(Direction::Vertical);
+}
+
+#[test]
+fn content_snaps_while_pressed_elsewhere_horizontal() {
+    content_snaps_while_pressed_elsewhere(Direction::Horizontal);
+}
+
+/// Marker of the even rows of the lists spawned by [`spawn_virtual_list`].
+#[derive(Component)]
+struct Striped;
+
+/// Spawns a 100 pixels high [`VirtualList`] of 10 000 rows and its scrollbar, counting the rows spawned in `spawned`, then lays them out.
+///
+/// Even rows are marked [`Striped`]. Rows sized by their content are given a 30 pixels high child.
+fn spawn_virtual_list(app: &mut App, row_height: RowHeight, spawned: Arc<AtomicUsize>) -> Entity {
+    let list = app
+        .world_mut()
+        .spawn((
+            Node {
+                width: Val::Px(100.0),
+                height: Val::Px(100.0),
+                overflow: Overflow::scroll_y(),
+                ..default()
+            },
+            VirtualList::new(10_000, row_height, move |index, row| {
+                spawned.fetch_add(1, Ordering::Relaxed);
+                if index % 2 == 0 {
+                    row.insert(Striped);
+                }
+                if let RowHeight::Estimated(_) = row_height {
+                    row.insert(Children::spawn_one(Node {
+                        height: Val::Px(30.0),
+                        ..default()
+                    }));
+                }
+            }),
+            Scrollable::spawn_one(Node {
+                width: Val::Px(10.0),
+                height: Val::Px(100.0),
+                ..default()
+            }),
+        ))
+        .id();
+    app.update();
+    app.update();
+    list
+}
+
+/// Returns the spawned rows of `list` by index.
+fn virtual_rows(app: &mut App, list: Entity) -> Vec<(usize, Entity)> {
+    let mut rows = app
+        .world_mut()
+        .query::<(Entity, &VirtualRow)>()
+        .iter(app.world())
+        .filter(|(_, row)| row.list() == list)
+        .map(|(entity, row)| (row.index(), entity))
+        .collect::<Vec<_>>();
+    rows.sort();
+    rows
+}
+
+/// Returns the height of the node holding the rows of `list`.
+fn virtual_list_height(app: &App, list: Entity) -> Val {
+    let content = app.world().get::<Children>(list).unwrap()[0];
+    app.world().get::<Node>(content).unwrap().height
+}
+
+#[test]
+fn virtual_list_spawns_visible_rows_only() {
+    let mut app = app();
+    let spawned = Arc::new(AtomicUsize::new(0));
+    let list = spawn_virtual_list(&mut app, RowHeight::Fixed(20.0), spawned.clone());
+    assert_eq!(virtual_list_height(&app, list), Val::Px(200_000.0));
+    let metrics = app.world().get::<ScrollMetrics>(list).unwrap();
+    assert_eq!(metrics.content_size().y, 200_000.0);
+
+    // Five rows fill the viewport, followed by two overscan rows
+    let rows = virtual_rows(&mut app, list);
+    let indices = rows.iter().map(|&(index, _)| index).collect::<Vec<_>>();
+    assert_eq!(indices, (0..7).collect::<Vec<_>>());
+    assert_eq!(spawned.load(Ordering::Relaxed), 7);
+
+    // Rows scrolled out of view are despawned and the rows scrolled into view spawned
+    scroll(&mut app, list, Vec2::new(0.0, -1000.0));
+    let scrolled_rows = virtual_rows(&mut app, list);
+    let indices = scrolled_rows
+        .iter()
+        .map(|&(index, _)| index)
+        .collect::<Vec<_>>();
+    assert_eq!(indices, (48..57).collect::<Vec<_>>());
+    assert!(
+        rows.iter()
+            .all(|&(_, row)| app.world().get_entity(row).is_err())
+    );
+    assert_eq!(spawned.load(Ordering::Relaxed), 16);
+    let row = app.world().get::<Node>(scrolled_rows[0].1).unwrap();
+    assert_eq!(row.top, Val::Px(960.0));
+
+    // Mutating the list respawns every spawned row
+    app.world_mut()
+        .get_mut::<VirtualList>(list)
+        .unwrap()
+        .set_changed();
+    app.update();
+    let refreshed_rows = virtual_rows(&mut app, list);
+    let indices =
+        |rows: &[(usize, Entity)]| rows.iter().map(|&(index, _)| index).collect::<Vec<_>>();
+    assert_eq!(indices(&refreshed_rows), indices(&scrolled_rows));
+    assert!(
+        scrolled_rows
+            .iter()
+            .all(|&(_, row)| app.world().get_entity(row).is_err())
+    );
+    assert_eq!(spawned.load(Ordering::Relaxed), 25);
+}
+
+#[test]
+fn virtual_rows_only_hold_the_components_of_their_index() {
+    let mut app = app();
+    let list = spawn_virtual_list(&mut app, RowHeight::Fixed(20.0), Arc::default());
+    let striped = |app: &mut App| {
+        virtual_rows(app, list)
+            .into_iter()
+            .map(|(index, row)| (index, app.world().get::<Striped>(row).is_some()))
+            .collect::<Vec<_>>()
+    };
+    let expected = |indices: std::ops::Range<usize>| {
+        indices
+            .map(|index| (index, index % 2 == 0))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(striped(&mut app), expected(0..7));
+
+    // Scrolling by an odd number of rows shows odd rows where even ones were and the other way around
+    scroll(&mut app, list, Vec2::new(0.0, -60.0));
+    assert_eq!(striped(&mut app), expected(1..10));
+    scroll(&mut app, list, Vec2::new(0.0, -1000.0));
+    assert_eq!(striped(&mut app), expected(51..60));
+}
+
+#[test]
+fn virtual_list_measures_estimated_rows() {
+    let mut app = app();
+    let spawned = Arc::new(AtomicUsize::new(0));
+    let list = spawn_virtual_list(&mut app, RowHeight::Estimated(20.0), spawned);
+    app.update();
+    app.update();
+
+    // The six rows spawned to fill the viewport measure 30 pixels instead of the estimated 20
+    assert_eq!(
+        virtual_list_height(&app, list),
+        Val::Px(6.0 * 30.0 + 9_994.0 * 20.0)
+    );
+    let rows = virtual_rows(&mut app, list);
+    let indices = rows.iter().map(|&(index, _)| index).collect::<Vec<_>>();
+    assert_eq!(indices, (0..6).collect::<Vec<_>>());
+    for (index, row) in rows {
+        let node = app.world().get::<Node>(row).unwrap();
+        assert_eq!(node.top, Val::Px(30.0 * index as f32));
+    }
+}
+
+/// Spawns a scrollbar whose 500 pixels of laid out content stand for 1000 pixels of virtual content.
+fn spawn_virtual_scrollbar(app: &mut App, direction: Direction) -> Entities {
+    let entities = spawn_scrollbar(app, direction);
+    let size = Vec2::splat(100.0) + direction.along(900.0);
+    app.world_mut()
+        .entity_mut(entities.scrollable)
+        .insert(VirtualContentSize(size));
+    app.update();
+    entities
+}
+
+fn virtual_content_sizes_the_thumb(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_virtual_scrollbar(&mut app, direction);
+    let thumb_node = app.world().get::<Node>(entities.thumb).unwrap();
+    let length = match direction {
+        Direction::Vertical => thumb_node.height,
+        Direction::Horizontal => thumb_node.width,
+    };
+    assert_eq!(length, Val::Percent(10.0));
+    let thumb_cnode = app.world().get::<ComputedNode>(entities.thumb).unwrap();
+    assert_eq!(thumb_cnode.size[direction.axis()], 10.0);
+}
+
+#[test]
+fn virtual_content_sizes_the_thumb_vertical() {
+    virtual_content_sizes_the_thumb(Direction::Vertical);
+}
+
+#[test]
+fn virtual_content_sizes_the_thumb_horizontal() {
+    virtual_content_sizes_the_thumb(Direction::Horizontal);
+}
+
+fn virtual_content_clamps_the_scroll_position(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_virtual_scrollbar(&mut app, direction);
+    scroll(&mut app, entities.scrollable, Vec2::splat(-5000.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 900.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(90.0));
+    scroll(&mut app, entities.scrollable, Vec2::splat(5000.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 0.0);
+}
+
+#[test]
+fn virtual_content_clamps_the_scroll_position_vertical() {
+    virtual_content_clamps_the_scroll_position(Direction::Vertical);
+}
+
+#[test]
+fn virtual_content_clamps_the_scroll_position_horizontal() {
+    virtual_content_clamps_the_scroll_position(Direction::Horizontal);
+}
+
+fn virtual_content_maps_trough_clicks(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_virtual_scrollbar(&mut app, direction);
+    click_trough(&mut app, entities.scrollbar, 0.5);
+    assert_eq!(scroll_position(&app, &entities, direction), 450.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(45.0));
+}
+
+#[test]
+fn virtual_content_maps_trough_clicks_vertical() {
+    virtual_content_maps_trough_clicks(Direction::Vertical);
+}
+
+#[test]
+fn virtual_content_maps_trough_clicks_horizontal() {
+    virtual_content_maps_trough_clicks(Direction::Horizontal);
+}
+
+fn virtual_content_scales_thumb_drags(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_virtual_scrollbar(&mut app, direction);
+    // Twice as much virtual content as laid out content doubles the drag
+    drag(&mut app, entities.scrollbar, direction.along(5.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 40.0);
+}
+
+#[test]
+fn virtual_content_scales_thumb_drags_vertical() {
+    virtual_content_scales_thumb_drags(Direction::Vertical);
+}
+
+#[test]
+fn virtual_content_scales_thumb_drags_horizontal() {
+    virtual_content_scales_thumb_drags(Direction::Horizontal);
+}
+
+fn thumb_travels_within_border_padding_and_inset(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+    let mut track = app.world_mut().entity_mut(entities.scrollbar);
+    let mut node = track.get_mut::<Node>().unwrap();
+    node.border = UiRect::all(Val::Px(1.0));
+    node.padding = UiRect::all(Val::Px(1.0));
+    track.insert(ThumbInset(1.0));
+    app.update();
+    app.update();
+
+    // The thumb travels along the 94 pixels left in the track, and spans the 4 pixels left across it
+    // The layout rounds its length of 18.8 pixels
+    let axis = direction.axis();
+    let thumb_cnode = app.world().get::<ComputedNode>(entities.thumb).unwrap();
+    assert_eq!(thumb_cnode.size[axis], 19.0);
+    assert_eq!(thumb_cnode.size[1 - axis], 4.0);
+
+    // Clicking the middle of the track centers the thumb on it
+    click_trough(&mut app, entities.scrollbar, 0.5);
+    assert_eq!(scroll_position(&app, &entities, direction), 200.0);
+    let Val::Px(offset) = thumb_offset(&app, &entities, direction) else {
+        panic!("thumb offset is not in pixels");
+    };
+    assert_eq!(offset, 39.5);
+    let transform = app
+        .world()
+        .get::<UiGlobalTransform>(entities.thumb)
+        .unwrap();
+    assert_eq!(transform.translation[axis], 50.0);
+
+    // Clicking the start of the lane scrolls back to the start
+    click_trough(&mut app, entities.scrollbar, 3.0 / 100.0);
+    assert_eq!(scroll_position(&app, &entities, direction), 0.0);
+}
+
+#[test]
+fn thumb_travels_within_border_padding_and_inset_vertical() {
+    thumb_travels_within_border_padding_and_inset(Direction::Vertical);
+}
+
+#[test]
+fn thumb_travels_within_border_padding_and_inset_horizontal() {
+    thumb_travels_within_border_padding_and_inset(Direction::Horizontal);
+}
+
+fn scroll_metrics_are_maintained(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+    click_trough(&mut app, entities.scrollbar, 0.5);
+
+    let axis = direction.axis();
+    let metrics = app
+        .world()
+        .get::<ScrollMetrics>(entities.scrollable)
+        .unwrap();
+    assert!(metrics.is_overflowing());
+    assert_eq!(metrics.viewport_size()[axis], 100.0);
+    assert_eq!(metrics.content_size()[axis], 500.0);
+    assert_eq!(metrics.max_offset(), direction.along(400.0));
+    assert_eq!(metrics.progress(), direction.along(0.5));
+    let thumb_rect = metrics.thumb_rect();
+    assert_eq!(thumb_rect.min, direction.along(40.0));
+    assert_eq!(thumb_rect.max[axis], 60.0);
+    assert_eq!(thumb_rect.max[1 - axis], 10.0);
+}
+
+#[test]
+fn scroll_metrics_are_maintained_vertical() {
+    scroll_metrics_are_maintained(Direction::Vertical);
+}
+
+#[test]
+fn scroll_metrics_are_maintained_horizontal() {
+    scroll_metrics_are_maintained(Direction::Horizontal);
+}
+
+fn scroll_progress_is_synced_and_writable(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+    click_trough(&mut app, entities.scrollbar, 0.5);
+    let progress = app.world().get::<ScrollProgress>(entities.scrollable);
+    assert_eq!(progress, Some(&ScrollProgress(0.5)));
+
+    // Writing the progress scrolls the content and moves the thumb
+    app.world_mut()
+        .entity_mut(entities.scrollable)
+        .insert(ScrollProgress(0.25));
+    app.update();
+    assert_eq!(scroll_position(&app, &entities, direction), 100.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(20.0));
+    app.update();
+    assert_eq!(scroll_position(&app, &entities, direction), 100.0);
+}
+
+#[test]
+fn scroll_progress_is_synced_and_writable_vertical() {
+    scroll_progress_is_synced_and_writable(Direction::Vertical);
+}
+
+#[test]
+fn scroll_progress_is_synced_and_writable_horizontal() {
+    scroll_progress_is_synced_and_writable(Direction::Horizontal);
+}
+
+fn scroll_progress_is_restored_on_spawn(direction: Direction) {
+    let mut app = app();
+    // The progress is written before the node is laid out, as when restoring a saved position
+    let entities = spawn_scrollable_with(&mut app, direction, ScrollProgress(0.5));
+    app.update();
+    assert_eq!(scroll_position(&app, &entities, direction), 200.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(40.0));
+    app.update();
+    app.update();
+    assert_eq!(scroll_position(&app, &entities, direction), 200.0);
+    let progress = app.world().get::<ScrollProgress>(entities.scrollable);
+    assert_eq!(progress, Some(&ScrollProgress(0.5)));
+}
+
+#[test]
+fn scroll_progress_is_restored_on_spawn_vertical() {
+    scroll_progress_is_restored_on_spawn(Direction::Vertical);
+}
+
+#[test]
+fn scroll_progress_is_restored_on_spawn_horizontal() {
+    scroll_progress_is_restored_on_spawn(Direction::Horizontal);
+}
+
+fn linked_scrollables_scroll_together(direction: Direction) {
+    let mut app = app();
+    let first = spawn_scrollbar(&mut app, direction);
+    let second = spawn_scrollbar(&mut app, direction);
+    // Halve the content of the second node, which can then scroll by 150 pixels
+    let children = app.world().get::<Children>(second.scrollable).unwrap()[..5].to_vec();
+    for child in children {
+        app.world_mut().despawn(child);
+    }
+    let group = app.world_mut().spawn_empty().id();
+    let link = match direction {
+        Direction::Vertical => ScrollLink::vertical(group),
+        Direction::Horizontal => ScrollLink::horizontal(group),
+    };
+    app.world_mut().entity_mut(first.scrollable).insert(link);
+    app.world_mut()
+        .entity_mut(second.scrollable)
+        .insert(link.with_mode(ScrollLinkMode::Progress));
+    app.update();
+
+    // The second node follows the progress of the first one in the same frame, thumb included
+    scroll(&mut app, first.scrollable, Vec2::splat(-300.0));
+    assert_eq!(scroll_position(&app, &first, direction), 300.0);
+    assert_eq!(scroll_position(&app, &second, direction), 112.5);
+    assert_eq!(thumb_offset(&app, &second, direction), Val::Px(45.0));
+
+    // The first node follows the offset of the second one in pixels
+    scroll(&mut app, second.scrollable, Vec2::splat(-1000.0));
+    assert_eq!(scroll_position(&app, &second, direction), 150.0);
+    assert_eq!(scroll_position(&app, &first, direction), 150.0);
+
+    // Positions written by the link do not feed back
+    app.update();
+    assert_eq!(scroll_position(&app, &first, direction), 150.0);
+    assert_eq!(scroll_position(&app, &second, direction), 150.0);
+}
+
+#[test]
+fn linked_scrollables_scroll_together_vertical() {
+    linked_scrollables_scroll_together(Direction::Vertical);
+}
+
+#[test]
+fn linked_scrollables_scroll_together_horizontal() {
+    linked_scrollables_scroll_together(Direction::Horizontal);
+}
+
+#[test]
+fn table_regions_follow_the_body() {
+    let mut app = app();
+    let content = |width, height| {
+        Children::spawn_one(Node {
+            width: Val::Px(width),
+            height: Val::Px(height),
+            flex_shrink: 0.0,
+            ..default()
+        })
+    };
+    let header = app
+        .world_mut()
+        .spawn((ScrollTableHeader, content(300.0, 10.0)))
+        .id();
+    let columns = app
+        .world_mut()
+        .spawn((ScrollTableColumns, content(20.0, 300.0)))
+        .id();
+    let body = app
+        .world_mut()
+        .spawn((ScrollTableBody, content(300.0, 300.0)))
+        .id();
+    app.world_mut()
+        .spawn((
+            Node {
+                width: Val::Px(100.0),
+                height: Val::Px(100.0),
+                ..default()
+            },
+            ScrollTable::default()
+                .with_header_height(10.0)
+                .with_columns_width(20.0)
+                .with_scrollbar_width(10.0),
+        ))
+        .add_children(&[header, columns, body]);
+    // The scrollbars are spawned on the first frame, then their thumbs laid out on the next ones
+    app.update();
+    app.update();
+    app.update();
+    let position = |app: &App, entity| app.world().get::<ScrollPosition>(entity).unwrap().0;
+
+    // Scrolling the body vertically moves the frozen columns along, but not the header
+    scroll(&mut app, body, Vec2::new(0.0, -30.0));
+    assert_eq!(position(&app, body), Vec2::new(0.0, 30.0));
+    assert_eq!(position(&app, columns), Vec2::new(0.0, 30.0));
+    assert_eq!(position(&app, header), Vec2::ZERO);
+
+    // Scrolling the body horizontally moves the header along, but not the frozen columns
+    scroll(&mut app, body, Vec2::new(-20.0, 0.0));
+    assert_eq!(position(&app, body), Vec2::new(20.0, 30.0));
+    assert_eq!(position(&app, header), Vec2::new(20.0, 0.0));
+    assert_eq!(position(&app, columns), Vec2::new(0.0, 30.0));
+
+    // Scrolling the body along both axes at once moves both regions along
+    scroll(&mut app, body, Vec2::splat(10.0));
+    assert_eq!(position(&app, body), Vec2::new(10.0, 20.0));
+    assert_eq!(position(&app, header), Vec2::new(10.0, 0.0));
+    assert_eq!(position(&app, columns), Vec2::new(0.0, 20.0));
+
+    // Scrolling the header moves the body along, but not the frozen columns
+    scroll(&mut app, header, Vec2::splat(-20.0));
+    assert_eq!(position(&app, header), Vec2::new(30.0, 0.0));
+    assert_eq!(position(&app, body), Vec2::new(30.0, 20.0));
+    assert_eq!(position(&app, columns), Vec2::new(0.0, 20.0));
+
+    // The horizontal scrollbar of the header sits below the body
+    let scrollbar = app.world().get::<Scrollable>(header).unwrap().scrollbar();
+    let track = app.world().get::<ComputedNode>(scrollbar).unwrap();
+    assert_eq!(track.size, Vec2::new(70.0, 10.0));
+}
+
+fn sticky_headers_pin_and_push(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+    let section = |length| {
+        let mut node = Node {
+            flex_shrink: 0.0,
+            ..default()
+        };
+        match direction {
+            Direction::Vertical => node.height = Val::Px(length),
+            Direction::Horizontal => node.width = Val::Px(length),
+        }
+        node
+    };
+    // Two headers of 20 pixels, heading sections of 100 and 300 pixels
+    let mut scrollable = app.world_mut().entity_mut(entities.scrollable);
+    scrollable.despawn_related::<Children>();
+    scrollable.with_children(|parent| {
+        parent.spawn((section(20.0), StickyHeader));
+        parent.spawn(section(100.0));
+        parent.spawn((section(20.0), StickyHeader));
+        parent.spawn(section(300.0));
+    });
+    let children = scrollable.get::<Children>().unwrap();
+    let (first, second) = (children[0], children[2]);
+    app.update();
+    app.update();
+
+    let axis = direction.axis();
+    let scrollable_transform = *app
+        .world()
+        .get::<UiGlobalTransform>(entities.scrollable)
+        .unwrap();
+    let viewport_start = scrollable_transform.translation[axis] - 50.0;
+    // Returns the start of a header relative to the viewport, as drawn in this frame
+    let header_start = |app: &App, header| {
+        app.world()
+            .get::<UiGlobalTransform>(header)
+            .unwrap()
+            .translation[axis]
+            - 10.0
+            - viewport_start
+    };
+    let scroll_to = |app: &mut App, position| {
+        app.world_mut()
+            .entity_mut(entities.scrollable)
+            .insert(ScrollPosition(direction.along(position)));
+        app.update();
+    };
+
+    // The first header is pinned while its section is scrolled through
+    scroll_to(&mut app, 50.0);
+    assert_eq!(header_start(&app, first), 0.0);
+    assert_eq!(header_start(&app, second), 70.0);
+
+    // Then it is pushed out by the second header
+    scroll_to(&mut app, 110.0);
+    assert_eq!(header_start(&app, first), -10.0);
+    assert_eq!(header_start(&app, second), 10.0);
+
+    // The second header stays pinned until the end of the content
+    scroll_to(&mut app, 300.0);
+    assert_eq!(header_start(&app, first), -200.0);
+    assert_eq!(header_start(&app, second), 0.0);
+    let ui_transform = app.world().get::<UiTransform>(second).unwrap();
+    let translation = direction.along(180.0);
+    assert_eq!(
+        ui_transform.translation,
+        Val2::px(translation.x, translation.y)
+    );
+}
+
+#[test]
+fn sticky_headers_pin_and_push_vertical() {
+    sticky_headers_pin_and_push(Direction::Vertical);
+}
+
+#[test]
+fn sticky_headers_pin_and_push_horizontal() {
+    sticky_headers_pin_and_push(Direction::Horizontal);
+}
+
+fn sticky_header_hosts_a_scrollbar(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+    let section = |length| {
+        let mut node = Node {
+            flex_shrink: 0.0,
+            ..default()
+        };
+        match direction {
+            Direction::Vertical => node.height = Val::Px(length),
+            Direction::Horizontal => node.width = Val::Px(length),
+        }
+        node
+    };
+    // A header holding a small scrollable node and its scrollbar, heading a section of 500 pixels
+    let mut scrollable = app.world_mut().entity_mut(entities.scrollable);
+    scrollable.despawn_related::<Children>();
+    let mut track = Entity::PLACEHOLDER;
+    scrollable.with_children(|parent| {
+        parent
+            .spawn((section(20.0), StickyHeader))
+            .with_children(|header| {
+                let nested = header
+                    .spawn((
+                        Node {
+                            width: Val::Px(20.0),
+                            height: Val::Px(20.0),
+                            overflow: Overflow::scroll_x(),
+                            ..default()
+                        },
+                        children![Node {
+                            width: Val::Px(40.0),
+                            height: Val::Px(20.0),
+                            flex_shrink: 0.0,
+                            ..default()
+                        }],
+                    ))
+                    .id();
+                track = header
+                    .spawn((
+                        Node {
+                            width: Val::Px(20.0),
+                            height: Val::Px(10.0),
+                            ..default()
+                        },
+                        Scrollbar { scrollable: nested },
+                    ))
+                    .id();
+            });
+        parent.spawn(section(500.0));
+    });
+    let header = scrollable.get::<Children>().unwrap()[0];
+    app.update();
+    app.update();
+
+    let axis = direction.axis();
+    let translation = |app: &App, entity| {
+        app.world()
+            .get::<UiGlobalTransform>(entity)
+            .unwrap()
+            .translation[axis]
+    };
+    let track_offset = translation(&app, track) - translation(&app, header);
+
+    // The header is pinned without moving its scrollbar, which the next layout places along with it
+    app.world_mut()
+        .entity_mut(entities.scrollable)
+        .insert(ScrollPosition(direction.along(30.0)));
+    app.update();
+    let viewport_start = translation(&app, entities.scrollable) - 50.0;
+    assert_eq!(translation(&app, header) - 10.0, viewport_start);
+    app.update();
+    assert_eq!(translation(&app, header) - 10.0, viewport_start);
+    assert_eq!(
+        translation(&app, track) - translation(&app, header),
+        track_offset
+    );
+}
+
+#[test]
+fn sticky_header_hosts_a_scrollbar_vertical() {
+    sticky_header_hosts_a_scrollbar(Direction::Vertical);
+}
+
+#[test]
+fn sticky_header_hosts_a_scrollbar_horizontal() {
+    sticky_header_hosts_a_scrollbar(Direction::Horizontal);
+}
+
+fn content_wraps_around(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+    app.world_mut()
+        .entity_mut(entities.scrollable)
+        .insert(WrapAround::default());
+    let children = app
+        .world()
+        .get::<Children>(entities.scrollable)
+        .unwrap()
+        .to_vec();
+    let scroll_to = |app: &mut App, position| {
+        app.world_mut()
+            .entity_mut(entities.scrollable)
+            .insert(ScrollPosition(direction.along(position)));
+        app.update();
+    };
+
+    // Scrolling past the end moves the first child to the end
+    scroll_to(&mut app, 420.0);
+    assert_eq!(scroll_position(&app, &entities, direction), 370.0);
+    let wrap = app.world().get::<WrapAround>(entities.scrollable).unwrap();
+    assert_eq!(wrap.offset(), 50.0);
+    let wrapped = app.world().get::<Children>(entities.scrollable).unwrap();
+    assert_eq!(wrapped[..9], children[1..]);
+    assert_eq!(wrapped[9], children[0]);
+    // The thumb is 84% of a turn along the 80 pixels it can travel
+    let Val::Px(offset) = thumb_offset(&app, &entities, direction) else {
+        panic!("thumb offset is not in pixels");
+    };
+    assert!((offset - 67.2).abs() < 1e-3);
+
+    // Scrolling past the start moves the last children to the start
+    scroll_to(&mut app, -20.0);
+    assert_eq!(scroll_position(&app, &entities, direction), 380.0);
+    let wrap = app.world().get::<WrapAround>(entities.scrollable).unwrap();
+    assert_eq!(wrap.offset(), 150.0);
+    let wrapped = app.world().get::<Children>(entities.scrollable).unwrap();
+    assert_eq!(wrapped[..7], children[3..]);
+    assert_eq!(wrapped[7..], children[..3]);
+}
+
+#[test]
+fn content_wraps_around_vertical() {
+    content_wraps_around(Direction::Vertical);
+}
+
+#[test]
+fn content_wraps_around_horizontal() {
+    content_wraps_around(Direction::Horizontal);
+}
+
+fn pages_turn_one_at_a_time(direction: Direction) {
+    let mut app = app();
+    // A second per frame lets the page turning animation settle in a few frames
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+    let entities = spawn_scrollbar(&mut app, direction);
+    app.world_mut()
+        .entity_mut(entities.scrollable)
+        .insert(Paged::default());
+    let indicator = app
+        .world_mut()
+        .spawn(PageIndicator::new(entities.scrollable))
+        .id();
+    app.update();
+    app.update();
+    let settle = |app: &mut App| {
+        app.update();
+        app.update();
+    };
+    let dots = app.world().get::<Children>(indicator).unwrap().to_vec();
+    assert_eq!(dots.len(), 5);
+    let dot_color =
+        |app: &App, page: usize| app.world().get::<BackgroundColor>(dots[page]).unwrap().0;
+    assert_eq!(dot_color(&app, 0), Color::WHITE);
+
+    // A burst of small pixel scrolls turns a page once it adds up to half a page
+    for _ in 0..4 {
+        scroll(&mut app, entities.scrollable, Vec2::splat(-10.0));
+    }
+    assert_eq!(scroll_position(&app, &entities, direction), 0.0);
+    scroll(&mut app, entities.scrollable, Vec2::splat(-10.0));
+    // Scrolls are ignored while the page is turning
+    scroll(&mut app, entities.scrollable, Vec2::splat(-60.0));
+    settle(&mut app);
+    assert_eq!(scroll_position(&app, &entities, direction), 100.0);
+    assert_eq!(dot_color(&app, 0), Color::srgb(0.5, 0.5, 0.5));
+    assert_eq!(dot_color(&app, 1), Color::WHITE);
+
+    // Scrolling back starts adding up again
+    scroll(&mut app, entities.scrollable, Vec2::splat(-40.0));
+    scroll(&mut app, entities.scrollable, Vec2::splat(40.0));
+    assert_eq!(scroll_position(&app, &entities, direction), 100.0);
+    scroll(&mut app, entities.scrollable, Vec2::splat(10.0));
+    settle(&mut app);
+    assert_eq!(scroll_position(&app, &entities, direction), 0.0);
+
+    // Clicking a dot jumps to its page
+    click(&mut app, dots[3], Vec2::ZERO);
+    settle(&mut app);
+    assert_eq!(scroll_position(&app, &entities, direction), 300.0);
+    assert_eq!(dot_color(&app, 3), Color::WHITE);
+
+    // Dragging the thumb by half a page of content turns a page
+    drag(&mut app, entities.scrollbar, direction.along(15.0));
+    settle(&mut app);
+    assert_eq!(scroll_position(&app, &entities, direction), 400.0);
+
+    // Clicking the trough or writing the progress turns to the closest page
+    click_trough(&mut app, entities.scrollbar, 0.7);
+    settle(&mut app);
+    assert_eq!(scroll_position(&app, &entities, direction), 300.0);
+    app.world_mut()
+        .entity_mut(entities.scrollable)
+        .insert(ScrollProgress(0.3));
+    app.update();
+    settle(&mut app);
+    assert_eq!(scroll_position(&app, &entities, direction), 100.0);
+}
+
+#[test]
+fn pages_turn_one_at_a_time_vertical() {
+    pages_turn_one_at_a_time(Direction::Vertical);
+}
+
+#[test]
+fn pages_turn_one_at_a_time_horizontal() {
+    pages_turn_one_at_a_time(Direction::Horizontal);
+}
+
+/// Value sampled by a [`ScrollTimeline`].
+#[derive(Component, PartialEq, Debug)]
+struct Sampled(f32);
+
+fn scroll_timeline_samples_its_curve(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+    let curve = EasingCurve::new(0.0, 100.0, EaseFunction::Linear);
+    let target = app
+        .world_mut()
+        .spawn(ScrollTimeline::new(
+            entities.scrollable,
+            curve,
+            |value, entity| {
+                entity.insert(Sampled(value));
+            },
+        ))
+        .id();
+    app.update();
+    assert_eq!(app.world().get::<Sampled>(target), Some(&Sampled(0.0)));
+
+    // The curve is sampled in the frame the content scrolls
+    click_trough(&mut app, entities.scrollbar, 0.5);
+    assert_eq!(app.world().get::<Sampled>(target), Some(&Sampled(50.0)));
+    scroll(&mut app, entities.scrollable, Vec2::splat(-1000.0));
+    assert_eq!(app.world().get::<Sampled>(target), Some(&Sampled(100.0)));
+}
+
+#[test]
+fn scroll_timeline_samples_its_curve_vertical() {
+    scroll_timeline_samples_its_curve(Direction::Vertical);
+}
+
+#[test]
+fn scroll_timeline_samples_its_curve_horizontal() {
+    scroll_timeline_samples_its_curve(Direction::Horizontal);
+}
+
+#[test]
+fn systems_can_be_ordered_between_layout_and_the_thumb_update() {
+    let mut app = app();
+    spawn_scrollbar(&mut app, Direction::Vertical);
+    app.add_systems(
+        PostUpdate,
+        (|| {})
+            .after(bevy::ui::UiSystems::Layout)
+            .before(ScrollbarSystems)
+            .after(ScrollbarPreLayoutSystems),
+    );
+    app.update();
+}
@@ -0,0 +1,42 @@
+//! Tests of the helpers of the `testing` module.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_scrollbar::testing::{
+    simulate_thumb_drag, simulate_trough_click, simulate_wheel, thumb_entity,
+};
+use common::{Direction, app, scroll_position, spawn_scrollbar, thumb_offset};
+
+fn helpers_scroll(direction: Direction) {
+    let mut app = app();
+    let entities = spawn_scrollbar(&mut app, direction);
+    assert_eq!(
+        thumb_entity(app.world(), entities.scrollbar),
+        Some(entities.thumb)
+    );
+
+    simulate_wheel(app.world_mut(), entities.scrollable, Vec2::splat(-30.0));
+    app.update();
+    assert_eq!(scroll_position(&app, &entities, direction), 30.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(6.0));
+
+    simulate_thumb_drag(app.world_mut(), entities.scrollbar, direction.along(5.0)).unwrap();
+    app.update();
+    assert_eq!(scroll_position(&app, &entities, direction), 50.0);
+
+    simulate_trough_click(app.world_mut(), entities.scrollbar, 1.0);
+    app.update();
+    assert_eq!(scroll_position(&app, &entities, direction), 400.0);
+    assert_eq!(thumb_offset(&app, &entities, direction), Val::Px(80.0));
+}
+
+#[test]
+fn helpers_scroll_vertical() {
+    helpers_scroll(Direction::Vertical);
+}
+
+#[test]
+fn helpers_scroll_horizontal() {
+    helpers_scroll(Direction::Horizontal);
+}